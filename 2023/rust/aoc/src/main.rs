@@ -0,0 +1,14 @@
+use aoc_runner::day;
+
+fn main() -> std::process::ExitCode {
+    aoc_runner::main(2023, env!("CARGO_MANIFEST_DIR"), &[
+        day!(1, day_01),
+        day!(2, day_02),
+        day!(3, day_03),
+        day!(4, day_04),
+        day!(5, day_05),
+        day!(6, day_06),
+        day!(7, day_07),
+        day!(8, day_08),
+    ])
+}
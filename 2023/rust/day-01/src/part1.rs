@@ -5,7 +5,7 @@ pub fn process(input: &str) -> u32 {
         });
 
         let first = iterator.next().expect("should be a number.");
-        let last = match iterator.last() {
+        let last = match iterator.next_back() {
             Some(num) => num,
             None      => first,
         };
@@ -19,21 +19,8 @@ pub fn process(input: &str) -> u32 {
 mod tests {
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        let result = process(input);
-        assert_eq!(result, 142);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 55090);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::One, process);
     }
 }
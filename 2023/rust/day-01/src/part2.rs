@@ -21,7 +21,7 @@ pub fn process(input: &str) -> u32 {
             });
 
             let first = it.next().expect("first should be a number");
-            let last = match it.last() {
+            let last = match it.next_back() {
                 Some(num) => num,
                 None => first,
             };
@@ -37,24 +37,8 @@ pub fn process(input: &str) -> u32 {
 mod tests {
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-        let result = process(input);
-        assert_eq!(result, 281);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 54845);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::Two, process);
     }
 }
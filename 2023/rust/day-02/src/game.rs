@@ -0,0 +1,56 @@
+// The cubes shown in a single round of a game.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+// Every game in the input, such as `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+pub fn parse(input: &str) -> Vec<Game> {
+    input.lines().map(|line| {
+        let parts: Vec<&str> = line.split(": ").collect();
+        let id = parts[0].chars().filter(|c| c.is_numeric()).collect::<String>().parse::<u32>().unwrap_or(0);
+
+        let rounds = parts[1].split("; ").map(|round| {
+            let mut result = Round::default();
+
+            round.split(", ").for_each(|ball| {
+                let ball_total: u32 = ball.chars().filter(|c| c.is_numeric()).collect::<String>().parse().unwrap_or(0);
+
+                match ball.to_lowercase() {
+                    x if x.contains("red")   => result.red = ball_total,
+                    x if x.contains("green") => result.green = ball_total,
+                    x if x.contains("blue")  => result.blue = ball_total,
+                    _ => ()
+                };
+            });
+
+            result
+        })
+        .collect();
+
+        Game { id, rounds }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Game, Round};
+
+    #[test]
+    fn parses_games() {
+        let games = parse("Game 1: 3 blue, 4 red; 2 green\r\nGame 12: 1 red\r\n");
+        assert_eq!(games, [
+            Game { id: 1, rounds: vec![Round { red: 4, green: 0, blue: 3 }, Round { red: 0, green: 2, blue: 0 }] },
+            Game { id: 12, rounds: vec![Round { red: 1, green: 0, blue: 0 }] },
+        ]);
+    }
+}
@@ -0,0 +1,35 @@
+pub mod game;
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+use game::Game;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Vec<Game> {
+        game::parse(input)
+    }
+
+    fn part1(input: &Vec<Game>) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Vec<Game>) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
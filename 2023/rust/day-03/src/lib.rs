@@ -0,0 +1,35 @@
+pub mod part1;
+pub mod part2;
+pub mod schematic;
+
+use aoc_runner::Solution;
+use schematic::Schematic;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Schematic;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Schematic {
+        Schematic::parse(input)
+    }
+
+    fn part1(input: &Schematic) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Schematic) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::{BTreeSet, HashMap};
+use aoc_common::{Grid, Point};
+
+// A number in the engine schematic, which runs along a row from `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub start: Point,
+    pub length: usize,
+    pub value: u32,
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    // The number that each digit is part of, as an index into `numbers`.
+    digits: HashMap<Point, usize>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Self {
+        let grid = input.parse::<Grid<char>>().expect("the schematic to be a grid");
+        let mut numbers = Vec::new();
+        let mut digits = HashMap::new();
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let length = row[x..].iter().take_while(|cell| cell.is_ascii_digit()).count();
+                if length == 0 {
+                    x += 1;
+                    continue;
+                }
+
+                let value = row[x..x + length]
+                    .iter()
+                    .filter_map(|cell| cell.to_digit(10))
+                    .fold(0, |value, digit| value * 10 + digit);
+                for offset in 0..length {
+                    digits.insert(Point::new(x + offset, y), numbers.len());
+                }
+                numbers.push(Number { start: Point::new(x, y), length, value });
+                x += length;
+            }
+        }
+
+        Self { grid, numbers, digits }
+    }
+
+    // Every cell that isn't a number or a `.`.
+    pub fn symbols(&self) -> impl Iterator<Item = (Point, char)> + '_ {
+        self.grid
+            .iter()
+            .filter(|&(_, &cell)| cell != '.' && !cell.is_ascii_digit())
+            .map(|(point, &cell)| (point, cell))
+    }
+
+    // The numbers that touch a point, including diagonally. A number that
+    // touches it more than once is only given once.
+    pub fn numbers_around(&self, point: Point) -> impl Iterator<Item = &Number> {
+        self.grid
+            .neighbours8(point)
+            .filter_map(|neighbour| self.digits.get(&neighbour).copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|index| &self.numbers[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Point;
+    use super::{Number, Schematic};
+
+    #[test]
+    fn finds_numbers() {
+        let schematic = Schematic::parse("467..114\n...*....\n..35..63\n");
+        let values = schematic.numbers.iter().map(|number| number.value).collect::<Vec<_>>();
+        assert_eq!(values, [467, 114, 35, 63]);
+        assert_eq!(schematic.numbers[3], Number { start: Point::new(6, 2), length: 2, value: 63 });
+
+        let around = schematic.numbers_around(Point::new(3, 1)).map(|number| number.value).collect::<Vec<_>>();
+        assert_eq!(around, [467, 35]);
+    }
+
+    #[test]
+    fn symbols_on_the_edge() {
+        // A symbol in the last column used to be read past the end of its row.
+        let schematic = Schematic::parse("..12\n...#\n");
+        assert_eq!(schematic.symbols().collect::<Vec<_>>(), [(Point::new(3, 1), '#')]);
+        assert_eq!(schematic.numbers_around(Point::new(3, 1)).count(), 1);
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+use aoc_common::parse::{integer, integers, lines, whole};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, space0, space1},
+    IResult, sequence::{pair, preceded, separated_pair, tuple},
+};
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning: HashSet<u32>,
+    pub chosen: HashSet<u32>,
+}
+
+impl Card {
+    // How many of the chosen numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.chosen).count()
+    }
+}
+
+// A card such as `Card  1: 41 48 83 | 83 86  6`.
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    let (input, id) = preceded(pair(tag("Card"), space1), integer)(input)?;
+    let (input, (winning, chosen)) = preceded(
+        pair(char(':'), space0),
+        separated_pair(integers::<u32, _>, tuple((space0, char('|'), space0)), integers::<u32, _>),
+    )(input)?;
+
+    let card = Card { id, winning: winning.into_iter().collect(), chosen: chosen.into_iter().collect() };
+    Ok((input, card))
+}
+
+fn parse_cards(input: &str) -> IResult<&str, Vec<Card>> {
+    whole(lines(parse_card))(input)
+}
+
+pub fn parse(input: &str) -> Vec<Card> {
+    let (_, cards) = parse_cards(input).expect("valid cards");
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_cards};
+
+    #[test]
+    fn parses_cards() {
+        let cards = parse("Card 1: 41 48 | 83 41  6\r\nCard  2: 13 | 61\r\n");
+        assert_eq!(cards.iter().map(|card| (card.id, card.matches())).collect::<Vec<_>>(), [(1, 1), (2, 0)]);
+        assert_eq!(cards[0].chosen.len(), 3);
+    }
+
+    #[test]
+    fn rejects_bad_cards() {
+        assert!(parse_cards("Card 1: 41 | 83\nCard 2: 13 x | 61\nCard 3: 1 | 1\n").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+pub mod card;
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+use card::Card;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Vec<Card> {
+        card::parse(input)
+    }
+
+    fn part1(input: &Vec<Card>) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Vec<Card>) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Range;
+use aoc_common::parse::{integers, labelled, paragraphs, section, whole};
+use nom::{IResult, Parser, combinator::map_opt};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlmanacMap {
+    pub dest_start: u64,
+    pub source_start: u64,
+    pub length: u64,
+}
+
+impl AlmanacMap {
+    pub fn from(source: &u64) -> AlmanacMap {
+        AlmanacMap {
+            dest_start: *source,
+            source_start: *source,
+            length: 1,
+        }
+    }
+
+    pub fn contains_source(&self, source: &u64) -> bool {
+        let source_end = self.source_start + self.length;
+        source >= &self.source_start && source < &source_end
+    }
+
+    pub fn convert_source_to_dest(&self, source: u64) -> u64 {
+        // Determine if the destination is smaller or bigger than the source.
+        let change: i64 = self.source_start.abs_diff(self.dest_start) as i64;
+        let change: i64 = change * (if self.dest_start >= self.source_start {
+            1
+        } else {
+            -1
+        });
+
+        ((source as i64) + change) as u64
+    }
+
+    // The sources this moves, along with where they start in the destination.
+    pub fn shift(&self) -> (Range<u64>, u64) {
+        (self.source_start..self.source_start + self.length, self.dest_start)
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    // Each step from seeds to locations, in order.
+    pub maps: Vec<Vec<AlmanacMap>>,
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    whole(labelled("seeds", integers))(input)
+}
+
+// A map such as `seed-to-soil map:`, with a line for each range it moves.
+fn parse_map(input: &str) -> IResult<&str, Vec<AlmanacMap>> {
+    let range = map_opt(integers, |numbers: Vec<u64>| match numbers[..] {
+        [dest_start, source_start, length] => Some(AlmanacMap { dest_start, source_start, length }),
+        _ => None,
+    });
+
+    whole(section(range))
+        .map(|(_, maps)| maps)
+        .parse(input)
+}
+
+pub fn parse(input: &str) -> Almanac {
+    let mut paragraphs = paragraphs(input).into_iter();
+    let (_, seeds) = parse_seeds(paragraphs.next().unwrap_or_default()).expect("seeds can be found");
+
+    let maps = paragraphs
+        .map(|seed_map| parse_map(seed_map).expect("seed map can be found").1)
+        .collect();
+
+    Almanac { seeds, maps }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_map, AlmanacMap};
+
+    #[test]
+    fn parses_almanac() {
+        let almanac = parse("seeds: 79 14\r\n\r\nseed-to-soil map:\r\n50 98 2\r\n52 50 48\r\n\r\nsoil-to-fertilizer map:\r\n0 15 37\r\n");
+        assert_eq!(almanac.seeds, [79, 14]);
+        assert_eq!(almanac.maps, [
+            vec![
+                AlmanacMap { dest_start: 50, source_start: 98, length: 2 },
+                AlmanacMap { dest_start: 52, source_start: 50, length: 48 },
+            ],
+            vec![AlmanacMap { dest_start: 0, source_start: 15, length: 37 }],
+        ]);
+    }
+
+    #[test]
+    fn rejects_bad_maps() {
+        assert!(parse_map("seed-to-soil map:\n50 98 2\n52 50\n0 15 37").is_err());
+    }
+}
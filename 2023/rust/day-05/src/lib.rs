@@ -0,0 +1,35 @@
+pub mod almanac;
+pub mod part1;
+pub mod part2;
+
+use almanac::Almanac;
+use aoc_runner::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Almanac {
+        almanac::parse(input)
+    }
+
+    fn part1(input: &Almanac) -> u64 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Almanac) -> u64 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,71 +1,4 @@
-use nom::{
-    IResult,
-    bytes::complete::{tag, take_until},
-    character::complete::{multispace0, digit1, multispace1, newline},
-    sequence::tuple, multi::separated_list1,
-};
-
-#[derive(Clone, Copy, Debug)]
-struct AlmanacMap {
-    dest_start: u64,
-    source_start: u64,
-    length: u64,
-}
-
-impl AlmanacMap {
-    fn from(source: &u64) -> AlmanacMap {
-        AlmanacMap {
-            dest_start: *source,
-            source_start: *source,
-            length: 1,
-        }
-    }
-
-    fn contains_source(&self, source: &u64) -> bool {
-        let source_end = self.source_start + self.length;
-        source >= &self.source_start && source < &source_end
-    }
-
-    fn convert_source_to_dest(&self, source: u64) -> u64 {
-        // Determine if the destination is smaller or bigger than the source.
-        let change: i64 = self.source_start.abs_diff(self.dest_start) as i64;
-        let change: i64 = change * (if self.dest_start >= self.source_start {
-            1
-        } else {
-            -1
-        });
-
-        ((source as i64) + change) as u64
-    }
-}
-
-fn parse_space_list(input: &str) -> IResult<&str, Vec<u64>> {
-    let (_, numbers) = separated_list1(tag(" "), digit1)(input)?;
-    let numbers = numbers.into_iter().map(|number| number.parse::<u64>().unwrap()).collect();
-    Ok((input, numbers))
-}
-
-fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
-    let (input, _) = tuple((tag("seeds:"), multispace0))(input)?;
-    let (input, seeds) = separated_list1(multispace1, digit1)(input)?;
-    let (input, _) = multispace1(input)?;
-    let seeds = seeds.into_iter().map(|seed| seed.parse::<u64>().expect("a number")).collect::<Vec<u64>>();
-    Ok((input, seeds))
-}
-
-fn parse_map(input: &str) -> IResult<&str, Vec<AlmanacMap>> {
-    let (input, _) = tuple((take_until(":"), tag(":"), newline))(input)?;
-    let maps: Vec<AlmanacMap> = input.lines().map(|line| {
-        let (_, seed_map) = parse_space_list(line).expect("space separated list");
-        AlmanacMap {
-            dest_start: seed_map[0],
-            source_start: seed_map[1],
-            length: seed_map[2],
-        }
-    })
-    .collect();
-    Ok((input, maps))
-}
+use crate::almanac::{Almanac, AlmanacMap};
 
 fn get_almanac_map(seed: u64, seed_maps: &[AlmanacMap]) -> Option<AlmanacMap> {
     let seed_maps: Vec<AlmanacMap> = seed_maps
@@ -114,24 +47,9 @@ fn get_location_from_seed(seed: &u64, seed_maps: &[Vec<AlmanacMap>]) -> u64 {
     humidity_map.convert_source_to_dest(humidity)
 }
 
-pub fn process(input: &str) -> u64 {
-    let input = input.replace("\r\n", "\n");
-    let (input, seeds) = parse_seeds(&input).expect("seeds can be found");
-
-    let mut seed_maps = input.split("\n\n").collect::<Vec<&str>>().into_iter();
-
-    let (_, seed_to_soil)            = parse_map(seed_maps.next().unwrap()).expect("seed_to_soil can be found");
-    let (_, soil_to_fertilizer)      = parse_map(seed_maps.next().unwrap()).expect("soil_to_fertilizer can be found");
-    let (_, fertilizer_to_water)     = parse_map(seed_maps.next().unwrap()).expect("fertilizer_to_water can be found");
-    let (_, water_to_light)          = parse_map(seed_maps.next().unwrap()).expect("water_to_light can be found");
-    let (_, light_to_temperature)    = parse_map(seed_maps.next().unwrap()).expect("light_to_temperature can be found");
-    let (_, temperature_to_humidity) = parse_map(seed_maps.next().unwrap()).expect("temperature_to_humidity can be found");
-    let (_, humidity_to_location)    = parse_map(seed_maps.next().unwrap()).expect("humidity_to_location can be found");
-
-    let maps = vec![seed_to_soil, soil_to_fertilizer, fertilizer_to_water, water_to_light, light_to_temperature, temperature_to_humidity, humidity_to_location];
-
-    seeds.iter().fold(u64::MAX, |mut lowest_location, seed| {
-        let location = get_location_from_seed(seed, &maps);
+pub fn process(almanac: &Almanac) -> u64 {
+    almanac.seeds.iter().fold(u64::MAX, |mut lowest_location, seed| {
+        let location = get_location_from_seed(seed, &almanac.maps);
         if location < lowest_location {
             lowest_location = location;
         }
@@ -141,52 +59,11 @@ pub fn process(input: &str) -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use crate::almanac;
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
-        let result = process(input);
-        assert_eq!(result, 35);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 346433842);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::One, |input| process(&almanac::parse(input)));
     }
 }
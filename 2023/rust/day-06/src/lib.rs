@@ -0,0 +1,33 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+    type Answer = u64;
+
+    fn parse(input: &str) -> String {
+        aoc_runner::normalise(input)
+    }
+
+    fn part1(input: &String) -> u64 {
+        u64::from(part1::process(input))
+    }
+
+    fn part2(input: &String) -> u64 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
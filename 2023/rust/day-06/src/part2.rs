@@ -1,5 +1,5 @@
-use nom::{IResult, character::complete::{multispace1, digit1, newline}, multi::separated_list1, Parser, sequence::separated_pair};
-use nom_supreme::{ParserExt, tag::complete::tag};
+use aoc_common::parse::labelled;
+use nom::{IResult, character::complete::{digit1, line_ending, space1}, multi::separated_list1, Parser, sequence::separated_pair};
 
 #[derive(Debug)]
 struct Data {
@@ -20,45 +20,31 @@ impl Data {
             * 2
             // We need to add one for even number of seconds due to the way we
             // half the numbers.
-            - match self.time % 2 == 0 { true => 1, false => 0 }
+            - match self.time.is_multiple_of(2) { true => 1, false => 0 }
     }
 }
 
 fn div_half_floor(lhs: u64) -> u64 {
-    match lhs % 2 == 0 {
+    match lhs.is_multiple_of(2) {
         true => lhs / 2,
         false => (lhs - 1) / 2,
     }
 }
 
-fn parse_times(input: &str) -> IResult<&str, u64> {
-    tag("Time:")
-        .precedes(multispace1)
-        .precedes(
-            separated_list1(multispace1, digit1)
-            .map(|list| list.join("").parse::<u64>().expect("a valid number"))
-        )
-        .parse(input)
-}
-
-fn parse_distances(input: &str) -> IResult<&str, u64> {
-    tag("Distance:")
-        .precedes(multispace1)
-        .precedes(
-            separated_list1(multispace1, digit1)
-            .map(|list| list.join("").parse::<u64>().expect("a valid number"))
-        )
+// The numbers are really one number, with spaces in between its digits.
+fn kerned_number(input: &str) -> IResult<&str, u64> {
+    separated_list1(space1, digit1)
+        .map(|digits: Vec<&str>| digits.concat().parse::<u64>().expect("a valid number"))
         .parse(input)
 }
 
 fn parse_input(input: &str) -> IResult<&str, (u64, u64)> {
-    separated_pair(parse_times, newline, parse_distances)
+    separated_pair(labelled("Time", kerned_number), line_ending, labelled("Distance", kerned_number))
         .parse(input)
 }
 
 pub fn process(input: &str) -> u64 {
-    let input = input.replace("\r\n", "\n");
-    let (_, (time, distance)) = parse_input(&input).expect("to succeed");
+    let (_, (time, distance)) = parse_input(input).expect("to succeed");
 
     Data::new(time, distance).get_wins()
 }
@@ -77,19 +63,8 @@ mod tests {
         assert_eq!(super::div_half_floor(7), 3);
     }
 
-    #[test]
-    fn example_input() {
-        let input = "Time:      7  15   30
-Distance:  9  40  200";
-        let result = process(input);
-        assert_eq!(result, 71503);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 34934171);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::Two, process);
     }
 }
@@ -197,8 +197,6 @@ fn parse_hand(line: &str) -> Hand {
 }
 
 pub fn process(input: &str) -> u32 {
-    let input = input.replace("\r\n", "\n");
-
     let mut hands: Vec<Hand> = input
         .lines()
         .map(parse_hand)
@@ -216,22 +214,8 @@ pub fn process(input: &str) -> u32 {
 mod tests {
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        let result = process(input);
-        assert_eq!(result, 5905);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 253362743);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::Two, process);
     }
 }
@@ -0,0 +1,35 @@
+pub mod network;
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+use network::Network;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Network;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Network {
+        network::parse(input)
+    }
+
+    fn part1(input: &Network) -> u64 {
+        u64::from(part1::process(input))
+    }
+
+    fn part2(input: &Network) -> u64 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
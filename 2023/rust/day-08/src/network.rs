@@ -0,0 +1,103 @@
+use std::{collections::BTreeMap, fmt::Debug};
+use aoc_common::parse::{key_value, list, parenthesised};
+use nom::{IResult, branch::alt, bytes::complete::take_while_m_n, Parser, character::complete::{char, line_ending}, multi::many1, sequence::tuple};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn new(direction: char) -> Direction {
+        match direction {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => panic!("Invalid direction {direction:?}."),
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Element(pub String);
+
+impl Element {
+    pub fn new(source: &str) -> Element {
+        Element(source.to_owned())
+    }
+}
+
+impl Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Element({})", self.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    // Where each element leads, to the left and then the right.
+    pub elements: BTreeMap<Element, Vec<Element>>,
+}
+
+// Create our own parser, because the nom parser is for byte strings, not chars.
+fn is_alphanumeric(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(
+        alt((
+            char('L'),
+            char('R'),
+        ))
+    )
+    .parse(input)
+    .map(|(input, directions)| {
+        (input, directions.iter().map(|d| Direction::new(*d)).collect())
+    })
+}
+
+fn parse_element(input: &str) -> IResult<&str, Element> {
+    take_while_m_n(3, 3, is_alphanumeric)
+    .parse(input)
+    .map(|(input, letters)| (input, Element::new(letters)))
+}
+
+// Parse all Element rows such as `AAA = (BBB, CCC)`.
+fn parse_element_row(input: &str) -> IResult<&str, (Element, Vec<Element>)> {
+    key_value(parse_element, "=", parenthesised(list(",", parse_element)))
+        .parse(input)
+}
+
+// All the parsers acting together in a single function.
+fn parse_input(input: &str) -> IResult<&str, Network> {
+    let (input, directions) = parse_directions(input)?;
+    let (input, _) = tuple((line_ending, line_ending))(input)?;
+
+    let elements = input.lines()
+    // `parse_element_row` returns a tuple, we don't care for the leftovers.
+   .map(|line| parse_element_row(line).expect("a valid element row.").1)
+   .collect();
+
+    Ok((input, Network { directions, elements }))
+}
+
+pub fn parse(input: &str) -> Network {
+    // In theory there should be no more `input`.
+    let (_input, network) = parse_input(input).expect("valid input");
+    network
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Direction, Element};
+
+    #[test]
+    fn parses_network() {
+        let network = parse("LR\r\n\r\n11A = (11B, XXX)\r\n11B = (XXX, 11Z)\r\n");
+        assert_eq!(network.directions, [Direction::Left, Direction::Right]);
+        assert_eq!(network.elements.len(), 2);
+        assert_eq!(network.elements[&Element::new("11B")], [Element::new("XXX"), Element::new("11Z")]);
+    }
+}
@@ -1,157 +1,73 @@
-use std::{collections::BTreeMap, fmt::Debug};
-use nom::{IResult, branch::alt, bytes::complete::{tag, take_while_m_n}, Parser, character::complete::{char, newline}, multi::{many1, separated_list1}, sequence::{tuple, delimited}};
-use crate::helpers::lcm;
-
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn new(direction: char) -> Direction {
-        match direction {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => {
-                dbg!(direction);
-                panic!("Invalid direction.");
-            },
-        }
-    }
-}
-
-#[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct Element(String);
-
-impl Element {
-    fn new(source: &str) -> Element {
-        Element(source.to_owned())
-    }
-}
-
-impl Debug for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Element({})", self.0)
-    }
-}
-
-
-// Create our own parser, because the nom parser is for byte strings, not chars.
-fn is_alphanumeric(c: char) -> bool {
-    c.is_alphanumeric()
-}
-
-fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
-    many1(
-        alt((
-            char('L'),
-            char('R'),
-        ))
-    )
-    .parse(input)
-    .map(|(input, directions)| {
-        (input, directions.iter().map(|d| Direction::new(*d)).collect())
-    })
-}
-
-fn parse_element(input: &str) -> IResult<&str, Element> {
-    take_while_m_n(3, 3, is_alphanumeric)
-    .parse(input)
-    .map(|(input, letters)| (input, Element::new(letters)))
-}
-
-// Parse all Element rows such as `AAA = (BBB, CCC)`.
-fn parse_element_row(input: &str) -> IResult<&str, (Element, Vec<Element>)> {
-    let (input, key) = parse_element(input)?;
-    let (input, _) = tag(" = ")(input)?;
-    let (input, value) = delimited(
-        tag("("),
-        separated_list1(tag(", "), parse_element),
-        tag(")"))
-        .parse(input)?;
-
-    Ok((input, (key, value)))
-}
-
-// All the parsers acting together in a single function.
-fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, BTreeMap<Element, Vec<Element>>)> {
-    let (input, directions) = parse_directions(input)?;
-    let (input, _) = tuple((newline, newline))(input)?;
-
-    let elements: BTreeMap<Element, Vec<Element>> = input.lines()
-    // `parse_element_row` returns a tuple, we don't care for the leftovers.
-   .map(|line| parse_element_row(line).expect("a valid element row.").1)
-   .collect();
-
-    Ok((input, (directions, elements)))
-}
-
-pub fn process(input: &str) -> u32 {
-    let input = input.replace("\r\n", "\n");
-
-    // In theory there should be no more `input`.
-    let (_input, (directions, elements)) = parse_input(&input).expect("valid input");
-
-    let results = elements
+use std::iter;
+use aoc_common::{graph::find_cycle, math::crt};
+use crate::network::{Direction, Element, Network};
+
+pub fn process(network: &Network) -> u64 {
+    let Network { directions, elements } = network;
+
+    // A ghost's state is where it is along with how far through the directions
+    // it is, which always leads the same way, so sooner or later it goes round
+    // in a cycle.
+    let step = |&(element, index): &(&Element, usize)| {
+        let destinations = elements.get(element).expect("has destinations.");
+        let next_element = match directions[index] {
+            Direction::Left  => &destinations[0],
+            Direction::Right => &destinations[1],
+        };
+        (next_element, (index + 1) % directions.len())
+    };
+
+    let ghosts = elements
     .keys()
     .filter(|&key| key.0.ends_with('A'))
     .map(|element| {
-        let mut current_element = element;
+        let (prefix, cycle) = find_cycle((element, 0), step);
 
-        // Use find_map to keep iterating over the Elements.
-        // We will return None to iterate through the directions again.
-        // When we return Some, it will cause this infinite loop to end.
-        directions
-        .iter()
-        .cycle()
-        .enumerate()
-        .find_map(|(index, direction)| {
-            let next_element = match direction {
-                Direction::Left  => &elements.get(current_element).expect("has destinations.")[0],
-                Direction::Right => &elements.get(current_element).expect("has destinations.")[1],
-            };
+        // Whether the ghost is on Element(__Z) after each step up until it has
+        // been round its cycle once.
+        let finished = iter::successors(Some((element, 0)), |state| Some(step(state)))
+        .take(prefix + cycle)
+        .map(|(element, _)| element.0.ends_with('Z'))
+        .collect::<Vec<bool>>();
 
-            if next_element.0.ends_with('Z') {
-                Some(index + 1)
-            } else {
-                current_element = next_element;
-                None
-            }
-        })
-        .expect("Element(__Z) to exist.")
+        (prefix, cycle, finished)
     })
-    .collect::<Vec<usize>>();
+    .collect::<Vec<_>>();
+
+    // The ghosts might all finish before every one of them is in its cycle.
+    let finishes_after = |&(prefix, cycle, ref finished): &(usize, usize, Vec<bool>), steps: usize| {
+        finished[if steps < prefix { steps } else { prefix + (steps - prefix) % cycle }]
+    };
+    let start = ghosts.iter().map(|(prefix, _, _)| *prefix).max().unwrap_or(0).max(1);
+    if let Some(steps) = (1..start).find(|&steps| ghosts.iter().all(|ghost| finishes_after(ghost, steps))) {
+        return steps as u64;
+    }
 
-    lcm(&results) as u32
+    // After that each ghost finishes at some steps modulo its cycle, so they all
+    // finish together at the first steps that agree with one of those for
+    // every ghost.
+    let congruences = ghosts.iter().fold(vec![(0, 1)], |congruences, (prefix, cycle, finished)| {
+        let finishes = (*prefix..prefix + cycle).filter(|&steps| finished[steps]);
+        finishes
+        .flat_map(|steps| congruences.iter().filter_map(move |&congruence| crt([congruence, (steps as u64, *cycle as u64)])))
+        .collect()
+    });
+
+    let start = start as u64;
+    congruences
+    .into_iter()
+    .map(|(steps, modulus)| if steps >= start { steps } else { steps + (start - steps).div_ceil(modulus) * modulus })
+    .min()
+    .expect("the ghosts to all finish together.")
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::network;
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-        let result = process(input);
-        assert_eq!(result, 6);
-    }
-
-    #[ignore]
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 18157);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::Two, |input| process(&network::parse(input)));
     }
 }
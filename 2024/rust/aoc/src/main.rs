@@ -0,0 +1,8 @@
+use aoc_runner::day;
+
+fn main() -> std::process::ExitCode {
+    aoc_runner::main(2024, env!("CARGO_MANIFEST_DIR"), &[
+        day!(1, day_01),
+        day!(2, day_02),
+    ])
+}
@@ -0,0 +1,57 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+
+// The two lists of location IDs, one from each column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lists {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Lists;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Lists {
+        let (left, right) = input
+            .lines()
+            .map(|line| {
+                let mut splits = line.split_whitespace();
+                (
+                    splits.next().unwrap_or_default().parse::<u32>().unwrap_or_default(),
+                    splits.next().unwrap_or_default().parse::<u32>().unwrap_or_default(),
+                )
+            })
+            .unzip();
+
+        Lists { left, right }
+    }
+
+    fn part1(input: &Lists) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Lists) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_runner::Solution;
+    use super::{Lists, Puzzle};
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(Puzzle::parse("3   4\r\n4   3\r\n"), Lists { left: vec![3, 4], right: vec![4, 3] });
+    }
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,16 +1,8 @@
-pub fn process(input: &str) -> u32 {
-    let input = input.replace("\r\n", "\n");
+use crate::Lists;
 
-    let mut left = Vec::<u32>::new();
-    let mut right = Vec::<u32>::new();
-
-    input
-        .lines()
-        .for_each(|line| {
-            let mut splits = line.split_whitespace();
-            left.push(splits.next().unwrap_or_default().parse::<u32>().unwrap_or_default());
-            right.push(splits.next().unwrap_or_default().parse::<u32>().unwrap_or_default());
-        });
+pub fn process(lists: &Lists) -> u32 {
+    let mut left = lists.left.clone();
+    let mut right = lists.right.clone();
 
     left.sort();
     right.sort();
@@ -24,25 +16,12 @@ pub fn process(input: &str) -> u32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_runner::Solution;
+    use crate::Puzzle;
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-        let result = process(&input);
-        assert_eq!(result, 11);
-    }
-
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 1151792);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::One, |input| process(&Puzzle::parse(input)));
     }
 }
@@ -0,0 +1,48 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vec<u32>>;
+    type Answer = usize;
+
+    // Each report, as the levels on one line.
+    fn parse(input: &str) -> Vec<Vec<u32>> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace().map(|num| {
+                    num.parse::<u32>().unwrap_or_default()
+                })
+                .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Vec<u32>>) -> usize {
+        part1::process(input)
+    }
+
+    fn part2(input: &Vec<Vec<u32>>) -> usize {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_runner::Solution;
+    use super::Puzzle;
+
+    #[test]
+    fn parses_reports() {
+        assert_eq!(Puzzle::parse("7 6 4\r\n1 2\r\n"), [vec![7, 6, 4], vec![1, 2]]);
+    }
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,15 +1,14 @@
 use itertools::Itertools;
 
-pub fn process(input: &str) -> usize {
-    let input = input.replace("\r\n", "\n");
-    let lines = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace().map(|num| {
-                num.parse::<u32>().unwrap_or_default()
-            })
-            .tuple_windows::<(u32, u32)>()
-            .collect::<Vec<(u32, u32)>>()
+pub fn process(reports: &[Vec<u32>]) -> usize {
+    let lines = reports
+        .iter()
+        .map(|report| {
+            report
+                .iter()
+                .copied()
+                .tuple_windows::<(u32, u32)>()
+                .collect::<Vec<(u32, u32)>>()
         })
         .collect::<Vec<Vec<(u32, u32)>>>();
 
@@ -21,7 +20,7 @@ pub fn process(input: &str) -> usize {
         for (a, b) in &line {
             let diff = a.abs_diff(*b);
             // First we will compare the ascending direction.
-            match a.cmp(&b) {
+            match a.cmp(b) {
                 std::cmp::Ordering::Greater => {
                     if ascending.is_some() && ascending == Some(false) {
                         errors += 1;
@@ -63,7 +62,6 @@ pub fn process(input: &str) -> usize {
         }
 
         if is_safe {
-            dbg!(line.iter().map(|(a, _)| format!("{a} ")).collect::<String>().trim());
             safe_lines += 1;
         }
     }
@@ -73,26 +71,12 @@ pub fn process(input: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_runner::Solution;
+    use crate::Puzzle;
     use super::process;
 
-    #[test]
-    fn example_input() {
-        let input = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
-        let result = process(&input);
-        assert_eq!(result, 4);
-    }
-
-    #[ignore]
     #[test]
     fn real_answer() {
-        let input = std::env::current_dir().unwrap().display().to_string() + "/src/input.txt";
-        let input = std::fs::read_to_string(input).expect("input to exist");
-        let result = process(&input);
-        assert_eq!(result, 0);
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::Two, |input| process(&Puzzle::parse(input)));
     }
 }
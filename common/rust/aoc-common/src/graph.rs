@@ -0,0 +1,401 @@
+use std::{
+    cmp::{min, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+use crate::math::Integer;
+
+// Graphs here are never stored, but found as they are searched by calling
+// `neighbours` on each node, so they can be an adjacency map such as
+// `|node| map[node].clone()`, or a grid such as `|&point| grid.neighbours4(point)`.
+
+// The path from the start to `node`, following each node back to the one it
+// was first reached from.
+fn path_to<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, node: N) -> Vec<N> {
+    let mut path = vec![node];
+    while let Some(parent) = parents.get(path.last().expect("the path to have a node")) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+// The shortest path from `start` to the first node that satisfies `goal`,
+// counting each step as one. The path includes both ends, so it takes one step
+// fewer than its length.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I, mut goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return Some(path_to(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// How many steps it takes to reach each node that can be reached from
+// `start`, including `start` itself.
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+// Every node that can be reached from `start`, in the order that a depth-first
+// search would first visit them. It keeps its own stack, so long paths can't
+// overflow.
+pub fn dfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        // Reversed, so that the first neighbour is the first one visited.
+        let mut next = neighbours(&node).into_iter().collect::<Vec<_>>();
+        next.reverse();
+        stack.extend(next.into_iter().filter(|next| !seen.contains(next)));
+        order.push(node);
+    }
+    order
+}
+
+// The cheapest path from `start` to the first node that satisfies `goal`, along
+// with its cost, where `neighbours` gives each neighbour with the cost of
+// moving to it. Costs can't be negative.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I, goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::ZERO, goal)
+}
+
+// As `dijkstra`, but `heuristic` estimates the cost from each node to the goal
+// so that the search heads towards it. The path is only sure to be the cheapest
+// when the heuristic never overestimates, such as the Manhattan distance on a
+// grid.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The heap refers to nodes by where they are in `nodes`, so that they
+    // needn't be `Ord` themselves.
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::ZERO, 0))]);
+    let mut costs = HashMap::from([(start, C::ZERO)]);
+    let mut parents = HashMap::new();
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        // A cheaper way here has been found since this was queued.
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if goal(&node) {
+            return Some((path_to(&parents, node), cost));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if costs.get(&next).is_some_and(|&best| best <= cost) {
+                continue;
+            }
+            costs.insert(next.clone(), cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((cost + heuristic(&next), cost, nodes.len())));
+            nodes.push(next);
+        }
+    }
+    None
+}
+
+// Tarjan's algorithm, with the nodes numbered in the order they're found.
+struct Tarjan<N> {
+    numbers: HashMap<N, usize>,
+    nodes: Vec<N>,
+    // The lowest number each node is known to reach while it's on the stack.
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    components: Vec<Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Tarjan<N> {
+    fn number(&mut self, node: N) -> usize {
+        let number = self.nodes.len();
+        self.numbers.insert(node.clone(), number);
+        self.nodes.push(node);
+        self.low.push(number);
+        self.on_stack.push(true);
+        self.stack.push(number);
+        number
+    }
+
+    fn search<I: IntoIterator<Item = N>>(&mut self, root: N, neighbours: &mut impl FnMut(&N) -> I) {
+        // Each frame is a node along with the neighbours it has yet to look at,
+        // standing in for the recursion, so long paths can't overflow.
+        let number = self.number(root.clone());
+        let mut frames = vec![(number, neighbours(&root).into_iter().collect::<Vec<_>>().into_iter())];
+
+        while let Some((number, rest)) = frames.last_mut() {
+            let number = *number;
+            if let Some(next) = rest.next() {
+                match self.numbers.get(&next) {
+                    Some(&found) if self.on_stack[found] => self.low[number] = min(self.low[number], found),
+                    Some(_) => {},
+                    None => {
+                        let found = self.number(next.clone());
+                        frames.push((found, neighbours(&next).into_iter().collect::<Vec<_>>().into_iter()));
+                    },
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                self.low[parent] = min(self.low[parent], self.low[number]);
+            }
+
+            // Nothing here reaches further back, so it and everything above it
+            // on the stack are a component.
+            if self.low[number] == number {
+                let start = self.stack.iter().rposition(|&member| member == number).expect("the node to be on the stack");
+                let members = self.stack.split_off(start);
+                for &member in &members {
+                    self.on_stack[member] = false;
+                }
+                self.components.push(members.into_iter().map(|member| self.nodes[member].clone()).collect());
+            }
+        }
+    }
+}
+
+// The strongly connected components of `nodes` and everything they reach,
+// where each node in a component can reach all of the others. A component
+// comes after every component it leads to, and its nodes are in the order they
+// were found.
+pub fn strongly_connected<N, I>(nodes: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tarjan = Tarjan {
+        numbers: HashMap::new(),
+        nodes: Vec::new(),
+        low: Vec::new(),
+        on_stack: Vec::new(),
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for node in nodes {
+        if !tarjan.numbers.contains_key(&node) {
+            tarjan.search(node, &mut neighbours);
+        }
+    }
+    tarjan.components
+}
+
+// `nodes` and everything they reach, ordered so that each node comes before
+// its neighbours. `None` means that there's a cycle, which can't be ordered.
+pub fn topological_sort<N, I>(nodes: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let components = strongly_connected(nodes, &mut neighbours);
+
+    let mut order = Vec::with_capacity(components.len());
+    for mut component in components.into_iter().rev() {
+        let node = component.pop().expect("a component to have a node");
+        // A node on its own is only a cycle when it leads back to itself.
+        if !component.is_empty() || neighbours(&node).into_iter().any(|next| next == node) {
+            return None;
+        }
+        order.push(node);
+    }
+    Some(order)
+}
+
+// Follow `next` from `start` until it repeats, giving how many steps it takes
+// to reach the cycle and then how long the cycle is. This is Brent's
+// algorithm, which only keeps a couple of states however long the cycle is.
+pub fn find_cycle<N: Clone + Eq>(start: N, mut next: impl FnMut(&N) -> N) -> (usize, usize) {
+    // The tortoise waits while the hare runs on, and catches up to it at each
+    // power of two, until the hare has run around the cycle back to it.
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // Then with the hare a cycle ahead, they meet where the cycle starts.
+    let (mut tortoise, mut hare) = (start.clone(), start);
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+    (prefix, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use crate::{Grid, Point};
+    use super::{astar, bfs, bfs_distances, dfs, dijkstra, find_cycle, strongly_connected, topological_sort};
+
+    const MAZE: &str = "\
+        S.#.....\n\
+        .##.###.\n\
+        ....#...\n\
+        ##.##.#.\n\
+        ......#E\n";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = MAZE.parse::<Grid<char>>().unwrap();
+        let find = |cell| grid.iter().find(|&(_, &found)| found == cell).unwrap().0;
+        let (start, end) = (find('S'), find('E'));
+        (grid, start, end)
+    }
+
+    fn network() -> BTreeMap<char, Vec<char>> {
+        [('a', "b"), ('b', "ce"), ('c', "d"), ('d', "c"), ('e', "af"), ('f', ""), ('g', "f")]
+            .into_iter()
+            .map(|(node, neighbours)| (node, neighbours.chars().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn searches_grids() {
+        let (grid, start, end) = maze();
+        let open = |&point: &Point| grid.neighbours4(point).filter(|&next| grid[next] != '#').collect::<Vec<_>>();
+
+        let path = bfs(start, open, |&point| point == end).unwrap();
+        assert_eq!((path.len() - 1, path[0], path[path.len() - 1]), (15, start, end));
+        assert!(path.windows(2).all(|step| step[0].x.abs_diff(step[1].x) + step[0].y.abs_diff(step[1].y) == 1));
+        assert_eq!(bfs(start, open, |&point| point == Point::new(2, 0)), None);
+
+        let distances = bfs_distances(start, open);
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&end], 15);
+        assert_eq!(distances.len(), grid.iter().filter(|&(_, &cell)| cell != '#').count());
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let (grid, start, end) = maze();
+        // Climbing up costs more than going any other way.
+        let open = |&point: &Point| {
+            grid.neighbours4(point)
+                .filter(|&next| grid[next] != '#')
+                .map(move |next| (next, if next.y < point.y { 10u32 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |&point: &Point| (point.x.abs_diff(end.x) + point.y.abs_diff(end.y)) as u32;
+
+        let (path, cost) = dijkstra(start, open, |&point| point == end).unwrap();
+        assert_eq!((path[0], path[path.len() - 1], cost), (start, end, 33));
+        assert_eq!(astar(start, open, manhattan, |&point| point == end).map(|(_, cost)| cost), Some(33));
+        assert_eq!(dijkstra(end, open, |&point| point == start).map(|(_, cost)| cost), Some(69));
+        assert_eq!(dijkstra(start, open, |&point| point.x > 10), None);
+
+        let network = network();
+        let edges = |node: &char| network[node].iter().map(|&next| (next, 1usize)).collect::<Vec<_>>();
+        assert_eq!(dijkstra('a', edges, |&node| node == 'f'), Some((vec!['a', 'b', 'e', 'f'], 3)));
+    }
+
+    #[test]
+    fn walks_depth_first() {
+        let network = network();
+        assert_eq!(dfs('a', |node| network[node].clone()), ['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(dfs('g', |node| network[node].clone()), ['g', 'f']);
+    }
+
+    #[test]
+    fn finds_components() {
+        let network = network();
+        let components = strongly_connected(network.keys().copied(), |node| network[node].clone());
+        assert_eq!(components, [vec!['c', 'd'], vec!['f'], vec!['a', 'b', 'e'], vec!['g']]);
+        assert_eq!(topological_sort(network.keys().copied(), |node| network[node].clone()), None);
+
+        // Without `e` going back to `a` or `d` back to `c`, there's an order.
+        let acyclic = |&node: &char| network[&node].iter().copied().filter(|&next| (node, next) != ('e', 'a') && (node, next) != ('d', 'c')).collect::<Vec<_>>();
+        let order = topological_sort(network.keys().copied(), acyclic).unwrap();
+        assert_eq!(order.len(), network.len());
+        for (node, neighbours) in &network {
+            for next in acyclic(node) {
+                let position = |node| order.iter().position(|&found| found == node);
+                assert!(position(*node) < position(next), "{node} comes before {next} in {neighbours:?}");
+            }
+        }
+
+        // A node that leads to itself is a cycle too.
+        assert_eq!(topological_sort([1], |&node| vec![node]), None);
+        assert_eq!(strongly_connected([1], |&node| vec![node]), [vec![1]]);
+    }
+
+    #[test]
+    fn finds_cycles() {
+        // Three steps in, then around 3, 4, 5, 6, 7 and back to 3.
+        assert_eq!(find_cycle(0, |&n| if n < 7 { n + 1 } else { 3 }), (3, 5));
+        assert_eq!(find_cycle(0, |&n| (n + 1) % 4), (0, 4));
+        assert_eq!(find_cycle(9, |_| 2), (1, 1));
+        assert_eq!(find_cycle(2, |&n| n), (0, 1));
+
+        // Long enough that the hare has to wait for the tortoise a few times.
+        assert_eq!(find_cycle(0u64, |&n| if n < 1000 { n + 1 } else { 1000 - 600 }), (400, 601));
+    }
+}
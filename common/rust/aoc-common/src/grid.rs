@@ -0,0 +1,293 @@
+use std::{fmt, ops::{Index, IndexMut}, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // The point moved by `(dx, dy)`, unless that would go past zero.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point::new(self.x.checked_add_signed(dx)?, self.y.checked_add_signed(dy)?))
+    }
+}
+
+// The offsets to the neighbours of a point, clockwise from above, first without
+// and then with the diagonals.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+// A rectangular grid of cells, such as a puzzle's map of characters. The cells
+// are stored a row at a time, with `(0, 0)` in the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // A grid from its cells, given a row at a time. `None` means there aren't
+    // `width * height` of them.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (width.checked_mul(height)? == cells.len()).then_some(Self { width, height, cells })
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![cell; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    // Every point in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    // Every cell along with its point, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.column_cells(x))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column_cells(x))
+    }
+
+    // The cells down column `x`, which has none when the grid has no rows.
+    fn column_cells(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if self.height == 0 { &[] } else { &self.cells[x..] };
+        cells.iter().step_by(self.width)
+    }
+
+    // The neighbours above, right, below and left of a point that are within
+    // the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    // The neighbours of a point that are within the grid, including diagonally,
+    // clockwise from above.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &SURROUNDING)
+    }
+
+    fn neighbours(&self, point: Point, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Point> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| point.offset(dx, dy))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("({}, {}) is outside of the {}x{} grid", point.x, point.y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("({}, {}) is outside of the {width}x{height} grid", point.x, point.y))
+    }
+}
+
+// Draws the grid as it was parsed, a row on each line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    // A row that isn't as wide as the first one, where `row` counts from zero.
+    Ragged { row: usize, expected: usize, found: usize },
+    InvalidCell { point: Point, cell: char },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Ragged { row, expected, found } => {
+                write!(f, "row {row} is {found} cells wide, but the grid is {expected} cells wide")
+            },
+            ParseGridError::InvalidCell { point, cell } => write!(f, "`{cell}` at ({}, {}) is not a valid cell", point.x, point.y),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+// Each line is a row and each character is a cell, which is converted with
+// `TryFrom<char>`, so `Grid<char>` takes the map as it is.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        // Inputs end with a blank line, which isn't a row of the grid.
+        for (y, line) in text.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let start = cells.len();
+            for (x, cell) in line.chars().enumerate() {
+                let value = T::try_from(cell).map_err(|_| ParseGridError::InvalidCell { point: Point::new(x, y), cell })?;
+                cells.push(value);
+            }
+
+            let found = cells.len() - start;
+            match width {
+                Some(expected) if expected != found => return Err(ParseGridError::Ragged { row: y, expected, found }),
+                _ => width = Some(found),
+            }
+            height += 1;
+        }
+
+        Ok(Self { width: width.unwrap_or_default(), height, cells })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, ParseGridError, Point};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ();
+
+        fn try_from(cell: char) -> Result<Self, ()> {
+            match cell {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn parses_maps() {
+        let grid = "#..\r\n.#.\r\n".parse::<Grid<Tile>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&Tile::Wall));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&Tile::Open));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+
+        let empty = "".parse::<Grid<char>>().unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+
+        let trailing = "#.\n.#\n\n\r\n".parse::<Grid<Tile>>().unwrap();
+        assert_eq!((trailing.width(), trailing.height()), (2, 2));
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = "abc\ndef\nghi\n".parse::<Grid<char>>().unwrap();
+        let cells = |points: Vec<Point>| points.into_iter().map(|point| grid[point]).collect::<String>();
+
+        assert_eq!(cells(grid.neighbours4(Point::new(1, 1)).collect()), "bfhd");
+        assert_eq!(cells(grid.neighbours8(Point::new(1, 1)).collect()), "bcfihgda");
+        assert_eq!(cells(grid.neighbours4(Point::new(0, 0)).collect()), "bd");
+        assert_eq!(cells(grid.neighbours8(Point::new(2, 2)).collect()), "fhe");
+        assert_eq!(grid.neighbours8(Point::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn walks_rows_and_columns() {
+        let grid = "ab\ncd\nef\n".parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>(), ["ab", "cd", "ef"]);
+        assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ace", "bdf"]);
+        assert_eq!(grid.row(1), Some(&['c', 'd'][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.column(1).map(|column| column.collect::<String>()).as_deref(), Some("bdf"));
+        assert!(grid.column(2).is_none());
+
+        let (point, cell) = grid.iter().nth(3).unwrap();
+        assert_eq!((point, *cell), (Point::new(1, 1), 'd'));
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+    }
+
+    #[test]
+    fn walks_columns_without_rows() {
+        let grid = Grid::filled(3, 0, 'a');
+        assert_eq!(grid.columns().map(|column| column.count()).collect::<Vec<_>>(), [0, 0, 0]);
+        assert_eq!(grid.column(2).map(|column| column.count()), Some(0));
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn builds_grids() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[Point::new(1, 0)] = 5;
+        *grid.get_mut(Point::new(0, 1)).unwrap() += 2;
+        assert_eq!(Grid::from_cells(2, 2, vec![0, 5, 2, 0]), Some(grid.clone()));
+        assert_eq!(Grid::from_cells(2, 3, vec![0; 4]), None);
+        assert_eq!(grid.map(|&cell| cell > 0), Grid::from_cells(2, 2, vec![false, true, true, false]).unwrap());
+        assert!(grid.get_mut(Point::new(2, 0)).is_none());
+    }
+
+    #[test]
+    fn rejects_bad_maps() {
+        assert_eq!("..\n...\n".parse::<Grid<Tile>>(), Err(ParseGridError::Ragged { row: 1, expected: 2, found: 3 }));
+        assert_eq!("..\n\n..\n".parse::<Grid<Tile>>(), Err(ParseGridError::Ragged { row: 1, expected: 2, found: 0 }));
+        assert_eq!(".#\n.x\n".parse::<Grid<Tile>>(), Err(ParseGridError::InvalidCell { point: Point::new(1, 1), cell: 'x' }));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod graph;
+mod grid;
+pub mod math;
+pub mod parse;
+mod ranges;
+
+pub use grid::{Grid, ParseGridError, Point};
+pub use ranges::RangeSet;
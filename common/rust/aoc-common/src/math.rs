@@ -0,0 +1,303 @@
+use std::{fmt::Debug, ops::{Add, Div, Mul, Rem, Sub}};
+
+// The primitive integers, signed or not, up to `u128`. Everything here is
+// checked, so a result that doesn't fit is `None` rather than wrapping.
+pub trait Integer:
+    Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+// The signed integers, which the coefficients of the extended Euclidean
+// algorithm need.
+pub trait Signed: Integer {}
+
+macro_rules! integers {
+    ($($integer:ty),*) => {$(
+        impl Integer for $integer {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$integer>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$integer>::checked_div(self, other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$integer>::checked_rem(self, other)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$integer>::checked_neg(self)
+            }
+        }
+    )*};
+}
+
+integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+fn abs<T: Integer>(value: T) -> Option<T> {
+    if value < T::ZERO { value.checked_neg() } else { Some(value) }
+}
+
+// The greatest common divisor, which is never negative. It is only `None` for
+// a signed `MIN` and zero, whose divisor is one more than `MAX`.
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        // Only `MIN % -1` overflows, and `-1` divides everything.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    abs(a)
+}
+
+// The least common multiple, which is never negative, or `None` when it
+// doesn't fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    abs((a / gcd(a, b)?).checked_mul(b)?)
+}
+
+// The greatest common divisor of every value, where there being none gives
+// zero.
+pub fn gcd_of<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ZERO, gcd)
+}
+
+// The least common multiple of every value, where there being none gives one.
+pub fn lcm_of<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+// The extended Euclidean algorithm, giving `(g, x, y)` where `g` is the
+// greatest common divisor and `a * x + b * y == g`. Both coefficients are no
+// bigger than `a` and `b`, so they only overflow along with `gcd`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        // Only `MIN % -1` overflows, and `-1` divides everything.
+        let remainder = old_r.checked_rem(r).unwrap_or(T::ZERO);
+        // The coefficients after the last step are `a` and `b` over the
+        // divisor, which can be one more than `MAX`, so they're never needed.
+        if remainder == T::ZERO {
+            (old_r, old_x, old_y) = (r, x, y);
+            break;
+        }
+
+        let quotient = old_r / r;
+        (old_r, r) = (r, remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        return Some((old_r.checked_neg()?, old_x.checked_neg()?, old_y.checked_neg()?));
+    }
+    Some((old_r, old_x, old_y))
+}
+
+// `value` as the smallest residue modulo a positive `modulus`.
+fn residue<T: Integer>(value: T, modulus: T) -> T {
+    let value = value % modulus;
+    if value < T::ZERO { value + modulus } else { value }
+}
+
+// `(a + b) % modulus` for residues, without overflowing even near `MAX`.
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b { a - (modulus - b) } else { a + b }
+}
+
+// `(a * b) % modulus` for residues. When the product doesn't fit, it is added
+// up by doubling instead.
+fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b, mut product) = (a, b, T::ZERO);
+    while b != T::ZERO {
+        if b % two == T::ONE {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b = b / two;
+    }
+    product
+}
+
+// `base` to the power of `exponent`, modulo `modulus`, which must be positive.
+pub fn mod_pow<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    assert!(modulus > T::ZERO, "the modulus must be positive, not {modulus:?}");
+    assert!(exponent >= T::ZERO, "the exponent must not be negative, not {exponent:?}");
+
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exponent, mut power) = (residue(base, modulus), exponent, T::ONE % modulus);
+    while exponent != T::ZERO {
+        if exponent % two == T::ONE {
+            power = mul_mod(power, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / two;
+    }
+    power
+}
+
+// The `x` where `a * x` is one modulo `modulus`, which only exists when they
+// are coprime. The coefficients are kept as residues, so it works for unsigned
+// integers as well.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+
+    let (mut old_r, mut r) = (modulus, residue(a, modulus));
+    let (mut old_x, mut x) = (T::ZERO, T::ONE % modulus);
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r - quotient * r);
+        let step = mul_mod(quotient, x, modulus);
+        (old_x, x) = (x, add_mod(old_x, modulus - step, modulus));
+    }
+
+    (old_r == T::ONE).then_some(old_x)
+}
+
+// Solve a system of congruences, each `x ≡ residue (mod modulus)`, giving the
+// smallest `x` along with the modulus it repeats with. The moduli don't need to
+// be coprime, and `None` means that the congruences disagree, a modulus isn't
+// positive, or the combined modulus doesn't fit.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences.into_iter().try_fold((T::ZERO, T::ONE), |(residue_a, modulus_a), (residue_b, modulus_b)| {
+        if modulus_b <= T::ZERO {
+            return None;
+        }
+
+        let divisor = gcd(modulus_a, modulus_b)?;
+        let residue_b = residue(residue_b, modulus_b);
+        let difference = add_mod(residue_b, modulus_b - residue_a % modulus_b, modulus_b) % modulus_b;
+        if difference % divisor != T::ZERO {
+            return None;
+        }
+
+        // x = residue_a + modulus_a * k, where k solves
+        // modulus_a * k ≡ difference (mod modulus_b), after dividing through
+        // by the divisor they share.
+        let reduced = modulus_b / divisor;
+        let k = mul_mod(difference / divisor, mod_inverse(modulus_a / divisor, reduced)?, reduced);
+        let modulus = (modulus_a / divisor).checked_mul(modulus_b)?;
+        Some((add_mod(residue_a, modulus_a * k, modulus), modulus))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, extended_gcd, gcd, gcd_of, lcm, lcm_of, mod_inverse, mod_pow};
+
+    #[test]
+    fn finds_divisors() {
+        assert_eq!(gcd(48u32, 18), Some(6));
+        assert_eq!(gcd(-48i32, 18), Some(6));
+        assert_eq!(gcd(0u8, 0), Some(0));
+        assert_eq!(gcd(i32::MIN, 2), Some(2));
+        assert_eq!(gcd(i32::MIN, 0), None);
+        assert_eq!(gcd(i32::MIN, -1), Some(1));
+        assert_eq!(gcd(-1, i32::MIN), Some(1));
+        assert_eq!(gcd_of([12u64, 18, 30]), Some(6));
+        assert_eq!(gcd_of(Vec::<u64>::new()), Some(0));
+    }
+
+    #[test]
+    fn finds_multiples() {
+        assert_eq!(lcm(4u32, 6), Some(12));
+        assert_eq!(lcm(-4i64, 6), Some(12));
+        assert_eq!(lcm(0u32, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm(i64::MIN, -1), None);
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i64::MIN + 1, -1), Some(i64::MAX));
+        assert_eq!(lcm_of([2usize, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_of(Vec::<usize>::new()), Some(1));
+
+        // Too big for a `u64`, but not a `u128`.
+        let primes = [4_294_967_291u64, 4_294_967_279, 4_294_967_231];
+        assert_eq!(lcm_of(primes), None);
+        assert_eq!(lcm_of(primes.map(u128::from)), Some(79_228_160_909_397_609_687_688_407_659));
+    }
+
+    #[test]
+    fn extends_euclid() {
+        for (a, b) in [(240i64, 46), (-240, 46), (46, -240), (0, 5), (7, 0), (17, 17)] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(Some(g), gcd(a, b));
+            assert_eq!(a * x + b * y, g, "for {a} and {b}");
+        }
+
+        for (a, b) in [(i8::MIN, -1), (-1, i8::MIN), (i8::MIN, 1)] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(g, 1);
+            assert_eq!(i16::from(a) * i16::from(x) + i16::from(b) * i16::from(y), 1, "for {a} and {b}");
+        }
+        assert_eq!(extended_gcd(i8::MIN, 0), None);
+
+        // Nothing panics, and every answer that fits is right.
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let divisor = gcd(i16::from(a), i16::from(b)).unwrap();
+                assert_eq!(gcd(a, b).map(i16::from), (divisor <= 127).then_some(divisor));
+                let multiple = lcm(i16::from(a), i16::from(b)).unwrap();
+                assert_eq!(lcm(a, b).map(i16::from), (multiple <= 127).then_some(multiple));
+                if let Some((g, x, y)) = extended_gcd(a, b) {
+                    assert_eq!(i16::from(a) * i16::from(x) + i16::from(b) * i16::from(y), i16::from(g));
+                }
+                assert_eq!(extended_gcd(a, b).is_some(), divisor <= 127, "for {a} and {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_pow(4u32, 13, 497), 445);
+        assert_eq!(mod_pow(-2i32, 3, 5), 2);
+        assert_eq!(mod_pow(7u8, 0, 1), 0);
+        // The squares overflow a `u128`, so they are found by doubling.
+        assert_eq!(mod_pow(u128::MAX - 1, 2, u128::MAX), 1);
+        assert_eq!(mod_pow(2u128, 127, u128::MAX), 1 << 127);
+
+        assert_eq!(mod_inverse(3u32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(6u32, 9), None);
+        assert_eq!(mod_inverse(5u32, 0), None);
+        assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1i64, 4), (3, 6)]), Some((3, 12)));
+        assert_eq!(crt([(1u32, 4), (2, 6)]), None);
+        assert_eq!(crt([(1u32, 0)]), None);
+        assert_eq!(crt(Vec::<(u32, u32)>::new()), Some((0, 1)));
+        assert_eq!(crt([(0u8, 200), (0, 3)]), None);
+    }
+}
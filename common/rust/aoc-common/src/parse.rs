@@ -0,0 +1,186 @@
+use std::str::FromStr;
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{all_consuming, map_opt, opt, recognize},
+    error::ParseError,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+// The parsers here are generic over their errors, so they fit in with either
+// nom's own or `nom-supreme`'s `ErrorTree`.
+
+// An integer with an optional sign, which is an error for an unsigned type or
+// when it doesn't fit.
+pub fn integer<'a, T: FromStr, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, T, E> {
+    map_opt(recognize(pair(opt(one_of("+-")), digit1)), |number: &str| number.parse().ok())(input)
+}
+
+// Integers separated by spaces or tabs on a single line, such as
+// `79 14  55 13`.
+pub fn integers<'a, T: FromStr, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Vec<T>, E> {
+    separated_list1(space1, integer)(input)
+}
+
+// Items separated by `separator`, allowing spaces or tabs either side of it,
+// such as `list(",", integer)` for `1, 2,3`.
+pub fn list<'a, O, E: ParseError<&'a str>>(
+    separator: &'static str,
+    item: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E> {
+    separated_list1(tuple((space0, tag(separator), space0)), item)
+}
+
+// The value after a label and a colon, such as `labelled("Time", integers)`
+// for `Time:      7  15   30`.
+pub fn labelled<'a, O, E: ParseError<&'a str>>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, E> {
+    preceded(tuple((tag(label), char(':'), space0)), value)
+}
+
+// A section with a title line ending in a colon, followed by a line for each
+// item, giving the title along with the items:
+//
+//     seed-to-soil map:
+//     50 98 2
+//     52 50 48
+pub fn section<'a, O, E: ParseError<&'a str>>(
+    line: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, Vec<O>), E> {
+    pair(
+        terminated(take_till1(|c| matches!(c, ':' | '\r' | '\n')), pair(char(':'), line_ending)),
+        separated_list1(line_ending, line),
+    )
+}
+
+// A row that gives a key a value, such as `AAA = (BBB, CCC)`, which allows
+// spaces or tabs either side of the separator.
+pub fn key_value<'a, K, V, E: ParseError<&'a str>>(
+    key: impl Parser<&'a str, K, E>,
+    separator: &'static str,
+    value: impl Parser<&'a str, V, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V), E> {
+    separated_pair(key, tuple((space0, tag(separator), space0)), value)
+}
+
+pub fn parenthesised<'a, O, E: ParseError<&'a str>>(
+    inner: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, E> {
+    delimited(char('('), inner, char(')'))
+}
+
+// A line of each item, which is how most inputs are laid out. Like `section`
+// it stops at the first line that isn't an item, so wrap it in `whole` when
+// that should be an error instead.
+pub fn lines<'a, O, E: ParseError<&'a str>>(
+    line: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E> {
+    separated_list1(line_ending, line)
+}
+
+// A parser that has to use up all of its input, apart from any blank lines at
+// the end, so a bad row part way through is an error rather than where the
+// parsing quietly stops.
+pub fn whole<'a, O, E: ParseError<&'a str>>(
+    parser: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, E> {
+    all_consuming(terminated(parser, multispace0))
+}
+
+// Split an input into the paragraphs between its blank lines, without their
+// line endings. A line of only spaces counts as blank, and both `\n` and
+// `\r\n` are understood.
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    let mut paragraphs = Vec::new();
+    let (mut start, mut end, mut offset) = (None, 0, 0);
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        paragraphs.push(&input[start..end]);
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::alpha1, error::Error, IResult};
+    use super::{integer, integers, key_value, labelled, lines, list, paragraphs, parenthesised, section, whole};
+
+    type Result<'a, T> = IResult<&'a str, T, Error<&'a str>>;
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(integer::<i32, Error<&str>>("-42 rest"), Ok((" rest", -42)));
+        assert_eq!(integer::<i32, Error<&str>>("+7"), Ok(("", 7)));
+        assert!(integer::<u32, Error<&str>>("-42").is_err());
+        assert!(integer::<u8, Error<&str>>("256").is_err());
+        assert!(integer::<u8, Error<&str>>("x").is_err());
+
+        let numbers: Result<Vec<u64>> = integers("79 14  55\t13\n1");
+        assert_eq!(numbers, Ok(("\n1", vec![79, 14, 55, 13])));
+        let numbers: Result<Vec<i64>> = list(",", integer)("1, -2 ,3");
+        assert_eq!(numbers, Ok(("", vec![1, -2, 3])));
+    }
+
+    #[test]
+    fn parses_labels() {
+        let times: Result<Vec<u32>> = labelled("Time", integers)("Time:      7  15   30\nDistance: 9");
+        assert_eq!(times, Ok(("\nDistance: 9", vec![7, 15, 30])));
+        assert!(labelled::<Vec<u32>, Error<&str>>("Time", integers)("Distance: 9").is_err());
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = "seed-to-soil map:\r\n50 98 2\r\n52 50 48\r\n\r\nsoil-to-fertilizer map:\r\n0 15 37";
+        let parsed: Result<(&str, Vec<Vec<u64>>)> = section(integers)(input);
+        let (rest, (title, rows)) = parsed.unwrap();
+        assert_eq!(title, "seed-to-soil map");
+        assert_eq!(rows, [[50, 98, 2], [52, 50, 48]]);
+        assert_eq!(rest, "\r\n\r\nsoil-to-fertilizer map:\r\n0 15 37");
+    }
+
+    #[test]
+    fn parses_rows() {
+        let row: Result<(&str, Vec<&str>)> = key_value(alpha1, "=", parenthesised(list(",", alpha1)))("AAA = (BBB, CCC)");
+        assert_eq!(row, Ok(("", ("AAA", vec!["BBB", "CCC"]))));
+
+        let rows: Result<Vec<(&str, u32)>> = lines(key_value(alpha1, ":", integer))("a: 1\r\nb:2\nc");
+        assert_eq!(rows, Ok(("\nc", vec![("a", 1), ("b", 2)])));
+    }
+
+    #[test]
+    fn parses_whole_inputs() {
+        let rows: Result<Vec<Vec<u32>>> = whole(lines(integers))("1 2\r\n3\r\n\r\n");
+        assert_eq!(rows, Ok(("", vec![vec![1, 2], vec![3]])));
+
+        // A bad row in the middle would otherwise leave the rest unparsed.
+        let rows: Result<Vec<Vec<u32>>> = whole(lines(integers))("1 2\nx 3\n4\n");
+        assert!(rows.is_err());
+        let parsed: Result<(&str, Vec<Vec<u64>>)> = whole(section(integers))("a map:\n1 2\n3 x\n4 5");
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        assert_eq!(paragraphs("a\nb\n\nc\n"), ["a\nb", "c"]);
+        assert_eq!(paragraphs("a\r\nb\r\n\r\n  \r\nc\r\n"), ["a\r\nb", "c"]);
+        assert_eq!(paragraphs("\n\na\n\n\n"), ["a"]);
+        assert!(paragraphs("").is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{cmp::{max, min}, ops::{Add, Range, Sub}};
+
+// A set of values kept as the half-open ranges that cover them, so that huge
+// spans cost no more than small ones. The ranges are sorted, and never empty,
+// overlapping or touching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn normalised(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(index).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalised(ranges);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalised(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut ours, mut theirs) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        let mut ranges = Vec::new();
+
+        while let (Some(a), Some(b)) = (ours.peek(), theirs.peek()) {
+            let (start, end) = (max(a.start, b.start), min(a.end, b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Whichever ends first can't overlap anything else.
+            if a.end <= b.end {
+                ours.next();
+            } else {
+                theirs.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut first = 0;
+
+        for range in &self.ranges {
+            // Skip what ends before this range, which can't matter to any of
+            // the ranges after it either.
+            while other.ranges.get(first).is_some_and(|removed| removed.end <= range.start) {
+                first += 1;
+            }
+
+            let mut start = range.start;
+            for removed in other.ranges[first..].iter().take_while(|removed| removed.start < range.end) {
+                if start < removed.start {
+                    ranges.push(start..removed.start);
+                }
+                start = max(start, removed.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    // The ranges cut at each of the breakpoints that fall inside them, such as
+    // the edges of another set's ranges. These touch, so they aren't a set.
+    pub fn split(&self, breakpoints: impl IntoIterator<Item = T>) -> Vec<Range<T>> {
+        let mut breakpoints = breakpoints.into_iter().collect::<Vec<_>>();
+        breakpoints.sort();
+        breakpoints.dedup();
+
+        let mut pieces = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            let first = breakpoints.partition_point(|&breakpoint| breakpoint <= range.start);
+            for &breakpoint in breakpoints[first..].iter().take_while(|&&breakpoint| breakpoint < range.end) {
+                pieces.push(start..breakpoint);
+                start = breakpoint;
+            }
+            pieces.push(start..range.end);
+        }
+        pieces
+    }
+
+    // Move every value through a piecewise mapping, where each `(source,
+    // destination)` moves the values in `source` so that it starts at
+    // `destination`. Values that aren't in any source are left where they
+    // are, and where sources overlap the first of them wins.
+    pub fn map(&self, mapping: impl IntoIterator<Item = (Range<T>, T)>) -> Self
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let mut remaining = self.clone();
+        let mut mapped = Vec::new();
+
+        for (source, destination) in mapping {
+            let source = Self::from(source);
+            for piece in remaining.intersection(&source).ranges {
+                let start = source.ranges[0].start;
+                mapped.push(destination + (piece.start - start)..destination + (piece.end - start));
+            }
+            remaining = remaining.difference(&source);
+        }
+
+        mapped.extend(remaining.ranges);
+        Self::normalised(mapped)
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalised(vec![range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        Self::normalised(ranges.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+
+    fn set(ranges: &[(i32, i32)]) -> RangeSet<i32> {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    #[test]
+    fn merges_ranges() {
+        assert_eq!(set(&[(5, 8), (0, 2), (1, 3), (3, 4), (9, 9)]).ranges(), [0..4, 5..8]);
+
+        let mut ranges = set(&[(0, 2), (6, 8)]);
+        ranges.insert(2..6);
+        assert_eq!(ranges, RangeSet::from(0..8));
+        assert!(ranges.contains(0) && ranges.contains(7) && !ranges.contains(8));
+        assert_eq!(ranges.min(), Some(0));
+        assert!(RangeSet::<u8>::new().is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 5), (10, 15), (20, 25)]);
+        let b = set(&[(3, 12), (14, 21), (30, 40)]);
+
+        assert_eq!(a.union(&b).ranges(), [0..25, 30..40]);
+        assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12, 14..15, 20..21]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..14, 21..25]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 15..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&set(&[(1, 2), (3, 4)])).ranges(), [0..1, 2..3, 4..5, 10..15, 20..25]);
+    }
+
+    #[test]
+    fn splits_at_breakpoints() {
+        let a = set(&[(0, 10), (20, 30)]);
+        assert_eq!(a.split([5, 0, 25, 10, 5, 40]), [0..5, 5..10, 20..25, 25..30]);
+        assert_eq!(a.split([]), [0..10, 20..30]);
+    }
+
+    #[test]
+    fn maps_ranges() {
+        // The seed-to-soil map from 2023's day 5, where 98..100 moves to 50
+        // and 50..98 moves to 52.
+        let seeds: RangeSet<u64> = [79..93, 55..68].into_iter().collect();
+        let soil = seeds.map([(98..100, 50), (50..98, 52)]);
+        assert_eq!(soil.ranges(), [57..70, 81..95]);
+
+        let moved = set(&[(0, 10)]).map([(2..4, -10), (3..6, 100), (8..20, 50)]);
+        assert_eq!(moved.ranges(), [-10..-8, 0..2, 6..8, 50..52, 101..103]);
+    }
+}
@@ -0,0 +1,470 @@
+use std::{collections::BTreeMap, fmt::{self, Display}, fs, io, path::Path};
+use serde::Deserialize;
+use crate::{day::Part, input, submit::Verdict, Error};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Answers are usually numbers, but some puzzles want text, so both are allowed.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl Answer {
+    fn into_string(self) -> String {
+        match self {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    #[serde(default)]
+    part1_wrong: Vec<Answer>,
+    #[serde(default)]
+    part2_wrong: Vec<Answer>,
+    part1_too_low: Option<Answer>,
+    part2_too_low: Option<Answer>,
+    part1_too_high: Option<Answer>,
+    part2_too_high: Option<Answer>,
+    // Other users' inputs for the day, by name, which hold the same answers.
+    #[serde(default)]
+    inputs: BTreeMap<String, DayAnswers>,
+}
+
+// Everything that is known about a part, from the answers that were sent.
+#[derive(Debug, Default)]
+struct Known {
+    answer: Option<String>,
+    wrong: Vec<String>,
+    // The highest answer that was too low, and the lowest that was too high.
+    too_low: Option<String>,
+    too_high: Option<String>,
+}
+
+// Why an answer is already known to be wrong, without needing to send it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Mistake {
+    Repeated,
+    TooHigh(String),
+    TooLow(String),
+}
+
+impl fmt::Display for Mistake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mistake::Repeated => write!(f, "already submitted and wrong"),
+            Mistake::TooHigh(bound) => write!(f, "not below {bound}, which was too high"),
+            Mistake::TooLow(bound) => write!(f, "not above {bound}, which was too low"),
+        }
+    }
+}
+
+// Answers are compared as numbers when they are numbers, and can't be out of
+// bounds otherwise.
+fn number(answer: &str) -> Option<i128> {
+    answer.parse().ok()
+}
+
+// The verified answers for each day's own input, as kept in a year's
+// `answers.toml`. Days are tables such as `[day-05]`, holding `part1` and
+// `part2`, and a part that is left out hasn't been verified yet.
+//
+// Answers that were sent and turned out wrong are kept alongside them, as
+// `part1_wrong`, along with the closest bounds as `part1_too_low` and
+// `part1_too_high`.
+//
+// Other users' inputs have their answers in tables of their own, such as
+// `[day-05.inputs.alice]`. Everything is looked up by the input's name, where
+// `None` is the day's own input.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u8, Option<String>, Part), Known>);
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let days = toml::from_str::<BTreeMap<String, DayAnswers>>(text).map_err(|error| error.message().to_owned())?;
+
+        let mut answers = BTreeMap::new();
+        for (name, mut day) in days {
+            let number = crate::day::parse_name(&name).ok_or_else(|| format!("`{name}` is not a day, expected `day-XX`"))?;
+            let inputs = std::mem::take(&mut day.inputs);
+
+            let mut all = vec![(None, day)];
+            for (input, day) in inputs {
+                if !day.inputs.is_empty() {
+                    return Err(format!("`{name}.inputs.{input}` can't have inputs of its own"));
+                }
+                all.push((Some(input), day));
+            }
+
+            for (input, day) in all {
+                let parts = [
+                    (Part::One, day.part1, day.part1_wrong, day.part1_too_low, day.part1_too_high),
+                    (Part::Two, day.part2, day.part2_wrong, day.part2_too_low, day.part2_too_high),
+                ];
+
+                for (part, answer, wrong, too_low, too_high) in parts {
+                    let known = Known {
+                        answer: answer.map(Answer::into_string),
+                        wrong: wrong.into_iter().map(Answer::into_string).collect(),
+                        too_low: too_low.map(Answer::into_string),
+                        too_high: too_high.map(Answer::into_string),
+                    };
+                    answers.insert((number, input.clone(), part), known);
+                }
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    // Reads `answers.toml` from the workspace. A year without one simply has no
+    // verified answers yet.
+    pub fn load(workspace: &Path) -> Result<Answers, Error> {
+        let path = workspace.join(ANSWERS_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(source) => return Err(Error::ReadAnswers { path, source }),
+        };
+
+        Answers::parse(&text).map_err(|message| Error::InvalidAnswers { path, message })
+    }
+
+    fn known(&self, day: u8, input: Option<&str>, part: Part) -> Option<&Known> {
+        self.0.get(&(day, input.map(str::to_owned), part))
+    }
+
+    pub fn get(&self, day: u8, input: Option<&str>, part: Part) -> Option<&str> {
+        self.known(day, input, part)?.answer.as_deref()
+    }
+
+    // Whether an answer for a part that hasn't been verified yet is already
+    // known to be wrong.
+    pub fn mistake(&self, day: u8, input: Option<&str>, part: Part, answer: &str) -> Option<Mistake> {
+        let known = self.known(day, input, part)?;
+        if known.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(Mistake::Repeated);
+        }
+
+        let answer = number(answer)?;
+        if let Some(bound) = known.too_high.as_ref().filter(|bound| number(bound).is_some_and(|bound| answer >= bound)) {
+            return Some(Mistake::TooHigh(bound.clone()));
+        }
+        if let Some(bound) = known.too_low.as_ref().filter(|bound| number(bound).is_some_and(|bound| answer <= bound)) {
+            return Some(Mistake::TooLow(bound.clone()));
+        }
+
+        None
+    }
+}
+
+pub(crate) fn day_key(day: u8) -> String {
+    format!("day-{day:02}")
+}
+
+// Numbers are kept as numbers, as long as TOML can hold them.
+fn toml_value(answer: &str) -> toml_edit::Value {
+    match answer.parse::<i64>() {
+        Ok(number) => number.into(),
+        Err(_) => answer.into(),
+    }
+}
+
+fn value_text(value: &toml_edit::Value) -> Option<String> {
+    match value {
+        toml_edit::Value::Integer(number) => Some(number.value().to_string()),
+        toml_edit::Value::String(text) => Some(text.value().clone()),
+        _ => None,
+    }
+}
+
+// Change the table for a day's input in `answers.toml`, keeping the rest of the
+// file, and any comments in it, as they were.
+fn edit(workspace: &Path, day: u8, input: Option<&str>, change: impl FnOnce(&mut toml_edit::Table)) -> Result<(), Error> {
+    let path = workspace.join(ANSWERS_FILE);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(source) => return Err(Error::ReadAnswers { path, source }),
+    };
+
+    let invalid = |message: String| Error::InvalidAnswers { path: path.clone(), message };
+    let mut document = text
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|error| invalid(error.message().to_owned()))?;
+    let mut table = document
+        .entry(&day_key(day))
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .ok_or_else(|| invalid(format!("`{}` is not a table", day_key(day))))?;
+
+    if let Some(input) = input {
+        // Only the input's own table is written out, as `[day-05.inputs.alice]`.
+        let mut inputs = toml_edit::Table::new();
+        inputs.set_implicit(true);
+        table = table
+            .entry("inputs")
+            .or_insert(toml_edit::Item::Table(inputs))
+            .as_table_mut()
+            .and_then(|inputs| inputs.entry(input).or_insert(toml_edit::table()).as_table_mut())
+            .ok_or_else(|| invalid(format!("`{}.inputs.{input}` is not a table", day_key(day))))?;
+    }
+    change(table);
+
+    fs::write(&path, document.to_string()).map_err(|source| Error::WriteAnswers { path, source })
+}
+
+// Save a verified answer to `answers.toml`.
+pub fn record_answer(workspace: &Path, day: u8, input: Option<&str>, part: Part, answer: &str) -> Result<(), Error> {
+    edit(workspace, day, input, |table| {
+        table[&format!("part{}", part.number())] = toml_edit::value(toml_value(answer));
+    })
+}
+
+// Save an answer that was sent and turned out wrong to `answers.toml`, along
+// with the bound it gives when it was too high or too low.
+pub fn record_wrong(workspace: &Path, day: u8, input: Option<&str>, part: Part, answer: &str, verdict: Verdict) -> Result<(), Error> {
+    let key = |name: &str| format!("part{}_{name}", part.number());
+
+    edit(workspace, day, input, |table| {
+        let wrong = table
+            .entry(&key("wrong"))
+            .or_insert(toml_edit::value(toml_edit::Array::new()))
+            .as_array_mut();
+        if let Some(wrong) = wrong {
+            if !wrong.iter().any(|value| value_text(value).as_deref() == Some(answer)) {
+                wrong.push(toml_value(answer));
+            }
+        }
+
+        // Only the closest bound on each side is worth keeping.
+        let (name, closer): (_, fn(i128, i128) -> bool) = match verdict {
+            Verdict::TooHigh => ("too_high", |answer, bound| answer < bound),
+            Verdict::TooLow => ("too_low", |answer, bound| answer > bound),
+            _ => return,
+        };
+        let bound = table
+            .get(&key(name))
+            .and_then(toml_edit::Item::as_value)
+            .and_then(value_text)
+            .and_then(|bound| number(&bound));
+        let Some(answer_number) = number(answer) else {
+            return;
+        };
+        if bound.is_none_or(|bound| closer(answer_number, bound)) {
+            table[&key(name)] = toml_edit::value(toml_value(answer));
+        }
+    })
+}
+
+// Each year's workspace lives within a directory named after the year, such as
+// `2023/rust`.
+fn workspace_year(workspace: &Path) -> Option<u16> {
+    workspace
+        .ancestors()
+        .filter_map(|dir| dir.file_name()?.to_str()?.parse().ok())
+        .next()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Checked {
+    Verified,
+    Unverified,
+    // The input isn't there to be solved, such as on a machine that hasn't
+    // fetched it.
+    NoInput(String),
+}
+
+// Check each of the day's inputs with a known answer for the part, starting
+// with the day's own and then every other user's.
+fn check<A: Display>(day_dir: &Path, input_dir: Option<&Path>, part: Part, process: impl Fn(&str) -> A) -> Vec<(Option<String>, Checked)> {
+    let name = day_dir.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let day = crate::day::parse_name(name).unwrap_or_else(|| panic!("`{name}` is not a day crate"));
+    let workspace = day_dir.parent().expect("the day to be inside a workspace");
+    let answers = Answers::load(workspace).unwrap_or_else(|error| panic!("{error}"));
+
+    let Some(input_dir) = input_dir else {
+        let checked = match answers.get(day, None, part) {
+            Some(_) => Checked::NoInput(Error::NoInputDir.to_string()),
+            None => Checked::Unverified,
+        };
+        return vec![(None, checked)];
+    };
+
+    let year = workspace_year(workspace).expect("the workspace to be inside a year's directory");
+    let mut inputs = vec![(None, input::input_path(input_dir, year, day))];
+    inputs.extend(input::named_inputs(input_dir, year, day).into_iter().map(|named| (Some(named.name), named.path)));
+
+    inputs
+        .into_iter()
+        .map(|(input, path)| {
+            let Some(expected) = answers.get(day, input.as_deref(), part) else {
+                return (input, Checked::Unverified);
+            };
+
+            let text = match fs::read_to_string(&path) {
+                Ok(text) => text,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    return (input, Checked::NoInput(format!("there is no input at `{}`", path.display())));
+                },
+                Err(error) => panic!("could not read input `{}`: {error}", path.display()),
+            };
+
+            let whose = input.as_deref().map(|input| format!(" for {input}")).unwrap_or_default();
+            assert_eq!(process(&text).to_string(), expected, "{name} part {part}{whose} doesn't match `{ANSWERS_FILE}`");
+            (input, Checked::Verified)
+        })
+        .collect()
+}
+
+// The `real_answer` test for a part, which solves the day's own input and
+// checks it against `answers.toml`, along with any other user's input that has
+// an answer there. `manifest_dir` is the day's crate, as given by
+// `env!("CARGO_MANIFEST_DIR")`, so the test works from any directory. The
+// inputs are found in the input directory, which `AOC_INPUT_DIR` can override.
+//
+// A part without a known answer isn't solved at all, as it has nothing to be
+// checked against and some unfinished parts take a very long time. A missing
+// input skips the test rather than failing it, as inputs aren't in the
+// repository.
+pub fn check_answer<A: Display>(manifest_dir: &str, part: Part, process: impl Fn(&str) -> A) {
+    let day_dir = Path::new(manifest_dir);
+    let name = day_dir.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+
+    for (input, checked) in check(day_dir, input::input_dir().as_deref(), part, &process) {
+        let whose = input.map(|input| format!(" for {input}")).unwrap_or_default();
+        match checked {
+            Checked::Verified => {},
+            Checked::Unverified => eprintln!("{name} part {part}{whose} is unverified, there is no answer for it in `{ANSWERS_FILE}`"),
+            Checked::NoInput(reason) => eprintln!("skipping {name} part {part}{whose}, {reason}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+    use super::{check, record_answer, record_wrong, workspace_year, Answers, Checked, Mistake, ANSWERS_FILE};
+    use crate::{day::Part, submit::Verdict, testing::TempDir};
+
+    #[test]
+    fn parses_days() {
+        let answers = Answers::parse("[day-05]\npart1 = 35\npart2 = \"ABC\"\n\n[day-06]\npart1 = 288\n").unwrap();
+        assert_eq!(answers.get(5, None, Part::One), Some("35"));
+        assert_eq!(answers.get(5, None, Part::Two), Some("ABC"));
+        assert_eq!(answers.get(6, None, Part::One), Some("288"));
+        assert_eq!(answers.get(6, None, Part::Two), None);
+        assert_eq!(answers.get(7, None, Part::One), None);
+
+        let answers = Answers::parse("[day-08]\npart1 = 6\n\n[day-08.inputs.alice]\npart1 = 7\n").unwrap();
+        assert_eq!(answers.get(8, None, Part::One), Some("6"));
+        assert_eq!(answers.get(8, Some("alice"), Part::One), Some("7"));
+        assert_eq!(answers.get(8, Some("bob"), Part::One), None);
+    }
+
+    #[test]
+    fn rejects_mistakes() {
+        assert!(Answers::parse("[five]\npart1 = 35\n").is_err());
+        assert!(Answers::parse("[day-05]\npart3 = 35\n").is_err());
+        assert!(Answers::parse("[day-05]\npart1 = 35.5\n").is_err());
+        assert!(Answers::parse("[day-05.inputs.alice.inputs.bob]\npart1 = 35\n").is_err());
+    }
+
+    #[test]
+    fn records_answers() {
+        let dir = TempDir::new("answers");
+        fs::write(dir.join(ANSWERS_FILE), "# Kept.\n\n[day-05]\npart1 = 35\n").unwrap();
+
+        record_answer(&dir, 5, None, Part::Two, "46").unwrap();
+        record_answer(&dir, 6, None, Part::One, "ABC").unwrap();
+        record_answer(&dir, 5, Some("alice"), Part::One, "36").unwrap();
+        record_wrong(&dir, 5, Some("alice"), Part::Two, "40", Verdict::TooLow).unwrap();
+
+        let text = fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap();
+        assert!(text.starts_with("# Kept.\n"));
+        assert!(text.contains("[day-05.inputs.alice]\npart1 = 36\n"));
+        assert!(!text.contains("[day-05.inputs]"));
+        let answers = Answers::parse(&text).unwrap();
+        assert_eq!(answers.get(5, None, Part::One), Some("35"));
+        assert_eq!(answers.get(5, None, Part::Two), Some("46"));
+        assert_eq!(answers.get(6, None, Part::One), Some("ABC"));
+        assert_eq!(answers.get(5, Some("alice"), Part::One), Some("36"));
+        assert_eq!(answers.mistake(5, Some("alice"), Part::Two, "30"), Some(Mistake::TooLow("40".to_owned())));
+        assert_eq!(answers.mistake(5, None, Part::Two, "30"), None);
+    }
+
+    #[test]
+    fn knows_mistakes() {
+        let answers = Answers::parse("[day-08]\npart2_wrong = [50, 2000, \"abc\"]\npart2_too_low = 50\npart2_too_high = 2000\n").unwrap();
+        assert_eq!(answers.get(8, None, Part::Two), None);
+        assert_eq!(answers.mistake(8, None, Part::Two, "abc"), Some(Mistake::Repeated));
+        assert_eq!(answers.mistake(8, None, Part::Two, "2000"), Some(Mistake::Repeated));
+        assert_eq!(answers.mistake(8, None, Part::Two, "3000"), Some(Mistake::TooHigh("2000".to_owned())));
+        assert_eq!(answers.mistake(8, None, Part::Two, "12"), Some(Mistake::TooLow("50".to_owned())));
+        assert_eq!(answers.mistake(8, None, Part::Two, "1000"), None);
+        assert_eq!(answers.mistake(8, None, Part::One, "12"), None);
+    }
+
+    #[test]
+    fn records_wrong_answers() {
+        let dir = TempDir::new("wrong");
+        fs::write(dir.join(ANSWERS_FILE), "[day-08]\npart1 = 6\n").unwrap();
+
+        record_wrong(&dir, 8, None, Part::Two, "100", Verdict::TooLow).unwrap();
+        record_wrong(&dir, 8, None, Part::Two, "50", Verdict::TooLow).unwrap();
+        record_wrong(&dir, 8, None, Part::Two, "900", Verdict::TooHigh).unwrap();
+        record_wrong(&dir, 8, None, Part::Two, "900", Verdict::TooHigh).unwrap();
+        record_wrong(&dir, 8, None, Part::Two, "500", Verdict::Wrong).unwrap();
+
+        let text = fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap();
+        assert!(text.contains("part2_wrong = [100, 50, 900, 500]"));
+        assert!(text.contains("part2_too_low = 100"));
+        assert!(text.contains("part2_too_high = 900"));
+
+        let answers = Answers::parse(&text).unwrap();
+        assert_eq!(answers.get(8, None, Part::One), Some("6"));
+        assert_eq!(answers.mistake(8, None, Part::Two, "500"), Some(Mistake::Repeated));
+        assert_eq!(answers.mistake(8, None, Part::Two, "400"), None);
+    }
+
+    #[test]
+    fn year_of_workspace() {
+        assert_eq!(workspace_year(Path::new("/code/advent-of-code/2023/rust")), Some(2023));
+        assert_eq!(workspace_year(Path::new("/code/advent-of-code/rust")), None);
+    }
+
+    #[test]
+    fn checks_real_answers() {
+        let dir = TempDir::new("check");
+        let day_dir = dir.join("2023/rust/day-05");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(dir.join("2023/rust").join(ANSWERS_FILE), "[day-05]\npart1 = 3\n\n[day-05.inputs.bob]\npart1 = 1\n").unwrap();
+        let inputs = dir.join("inputs");
+
+        let count = |input: &str| input.lines().count();
+        let unsolved = |_: &str| -> u32 { panic!("to not be solved") };
+        assert_eq!(check(&day_dir, Some(&inputs), Part::Two, unsolved), [(None, Checked::Unverified)]);
+        assert!(matches!(check(&day_dir, None, Part::One, count)[..], [(None, Checked::NoInput(_))]));
+        let [(None, Checked::NoInput(reason))] = &check(&day_dir, Some(&inputs), Part::One, count)[..] else {
+            panic!("the missing input to be skipped");
+        };
+        assert!(reason.contains("2023/05.txt"));
+
+        fs::create_dir_all(inputs.join("2023/05")).unwrap();
+        fs::write(inputs.join("2023/05.txt"), "a\nb\nc\n").unwrap();
+        fs::write(inputs.join("2023/05/alice.txt"), "a\n").unwrap();
+        fs::write(inputs.join("2023/05/bob.txt"), "b\n").unwrap();
+        assert_eq!(
+            check(&day_dir, Some(&inputs), Part::One, count),
+            [(None, Checked::Verified), (Some("alice".to_owned()), Checked::Unverified), (Some("bob".to_owned()), Checked::Verified)],
+        );
+    }
+}
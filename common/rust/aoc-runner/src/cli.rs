@@ -0,0 +1,581 @@
+use std::{fs, path::{Path, PathBuf}, process::ExitCode, time::SystemTime};
+use clap::{Parser, Subcommand};
+use crate::{
+    answers::Answers,
+    client::{self, Client, Fetched},
+    day::{Day, Part},
+    input::{self, Request},
+    leaderboard::{self, Leaderboard},
+    progress::{self, Progress, Timings},
+    puzzle::{self, Archived},
+    report::{self, Format, Record, Status},
+    run,
+    submit::{self, Submission, Submitted, Throttle, Verdict},
+    Error,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc",
+    about = "Run the Advent of Code solutions for a year.",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: Args,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Download a day's input into the input directory, unless it is already there.
+    Fetch {
+        /// The year of the puzzle, such as `2023`.
+        year: u16,
+
+        /// The day of the puzzle, as either `5`, `05` or `day-05`.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Save it as another user's input, for when the session is theirs.
+        #[arg(long, value_parser = parse_name)]
+        name: Option<String>,
+    },
+
+    /// Save a puzzle's description as Markdown, and each of its examples to the day's `examples`.
+    Puzzle {
+        /// The year of the puzzle, such as `2023`.
+        year: u16,
+
+        /// The day of the puzzle, as either `5`, `05` or `day-05`.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Read a saved copy of the puzzle's page, rather than downloading it.
+        #[arg(long, value_name = "PATH")]
+        file: Option<PathBuf>,
+    },
+
+    /// Solve a part of a day, and submit its answer unless it is already known.
+    Submit {
+        /// The year of the puzzle, such as `2023`.
+        year: u16,
+
+        /// The day of the puzzle, as either `5`, `05` or `day-05`.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// The part to submit, either 1 or 2.
+        #[arg(value_parser = parse_part)]
+        part: Part,
+
+        /// Solve another user's input instead, for when the session is theirs.
+        #[arg(long, value_parser = parse_name)]
+        name: Option<String>,
+    },
+
+    /// Show a private leaderboard, with how long each member took for each part.
+    Leaderboard {
+        /// The year of the leaderboard, such as `2023`.
+        year: u16,
+
+        /// The leaderboard's id, which is the number at the end of its URL.
+        #[arg(required_unless_present = "file")]
+        id: Option<u64>,
+
+        /// Read a saved copy of the leaderboard's JSON, rather than downloading it.
+        #[arg(long, value_name = "PATH", conflicts_with = "id")]
+        file: Option<PathBuf>,
+
+        /// Only show a single day.
+        #[arg(long, value_parser = parse_day)]
+        day: Option<u8>,
+
+        /// Show how long our own solutions take next to each day.
+        #[arg(long)]
+        timings: bool,
+    },
+
+    /// Show the stars for each year, and how long each solved day last took.
+    Progress {
+        /// Rewrite the progress section of the repository's `README.md` too.
+        #[arg(long)]
+        readme: bool,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct Args {
+    /// The year of the puzzle, such as `2023`.
+    #[arg(required = true)]
+    year: Option<u16>,
+
+    /// The day of the puzzle, as either `5`, `05` or `day-05`.
+    #[arg(value_parser = parse_day, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Run every day in the workspace, and summarise them in a table.
+    #[arg(long, conflicts_with_all = ["day", "input", "example"])]
+    all: bool,
+
+    /// Only run a single part of the puzzle.
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+
+    /// Read the input from this file, or from stdin when it is `-`.
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+
+    /// Use one of the day's examples, such as `1` for `examples/1.txt`.
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    example: Option<String>,
+
+    /// Parse and solve the puzzle this many times, to report timing statistics.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// How to write out the answers and timings.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+// A day as it's given on the command line, such as `5`, `05` or `day-05`.
+pub fn parse_day(day: &str) -> Result<u8, String> {
+    let number = day.strip_prefix("day-").unwrap_or(day);
+
+    match number.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{day}` is not a day between 1 and 25")),
+    }
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    part.parse::<u8>()
+        .ok()
+        .and_then(Part::new)
+        .ok_or_else(|| format!("`{part}` is not a part, expected 1 or 2"))
+}
+
+// Names are used for files and in `answers.toml`, so they are kept simple.
+fn parse_name(name: &str) -> Result<String, String> {
+    let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    if name.is_empty() || !name.chars().all(valid) {
+        return Err(format!("`{name}` is not a name, use only letters, numbers, `-` and `_`"));
+    }
+    Ok(name.to_owned())
+}
+
+impl Args {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+// Each year's runner only has that year's solutions, and only knows where that
+// year's days are.
+fn check_year(year: u16, found: u16) -> Result<(), Error> {
+    if found == year {
+        Ok(())
+    } else {
+        Err(Error::WrongYear { expected: year, found })
+    }
+}
+
+fn run(year: u16, workspace: &Path, days: &[Day], args: &Args) -> Result<Vec<Record>, Error> {
+    check_year(year, args.year.unwrap_or_default())?;
+
+    let answers = Answers::load(workspace)?;
+    let input_dir = input::input_dir().ok_or(Error::NoInputDir)?;
+    let parts = args.parts();
+    let repeat = args.repeat as usize;
+
+    let Some(number) = args.day else {
+        // Every crate in the workspace is reported, even those the runner
+        // doesn't know about, so that nothing is silently skipped.
+        let mut numbers = run::workspace_days(workspace);
+        numbers.extend(days.iter().map(|day| day.day));
+        numbers.sort();
+        numbers.dedup();
+
+        let records = numbers
+            .into_iter()
+            .flat_map(|number| match days.iter().find(|day| day.day == number) {
+                Some(day) => {
+                    let day_dir = workspace.join(day.name());
+                    let default = input::input_path(&input_dir, year, day.day);
+                    let named = input::named_inputs(&input_dir, year, day.day);
+                    let request = Request { named: &named, ..Request::new(&day_dir, &default) };
+                    run::run_day(year, day, &answers, &request, &parts, repeat)
+                },
+                None => parts
+                    .iter()
+                    .map(|&part| Record::from_error(year, number, part, Status::Error, &Error::UnregisteredDay(number).to_string()))
+                    .collect(),
+            })
+            .collect();
+
+        return Ok(records);
+    };
+
+    let day = days
+        .iter()
+        .find(|day| day.day == number)
+        .ok_or(Error::UnknownDay(number))?;
+
+    let day_dir = workspace.join(day.name());
+    let default = input::input_path(&input_dir, year, day.day);
+    let named = input::named_inputs(&input_dir, year, day.day);
+    let request = Request {
+        path: args.input.as_deref(),
+        example: args.example.as_deref(),
+        day_dir: &day_dir,
+        default: &default,
+        named: &named,
+        from_environment: true,
+    };
+
+    Ok(run::run_day(year, day, &answers, &request, &parts, repeat))
+}
+
+fn fetch(year: u16, found: u16, day: u8, name: Option<&str>) -> ExitCode {
+    let fetched = check_year(year, found)
+        .and_then(|()| input::input_dir().ok_or(Error::NoInputDir))
+        .and_then(|input_dir| client::fetch_input(Client::from_environment, &input_dir, year, day, name));
+
+    match fetched {
+        Ok(Fetched::Downloaded(path)) => println!("Saved the input for {year} day {day} to `{}`", path.display()),
+        Ok(Fetched::Cached(path)) => println!("The input for {year} day {day} is already at `{}`", path.display()),
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        },
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn archive_puzzle(year: u16, workspace: &Path, found: u16, day: u8, file: Option<&Path>) -> Result<Archived, Error> {
+    check_year(year, found)?;
+
+    let day_dir = workspace.join(format!("day-{day:02}"));
+    if !day_dir.is_dir() {
+        return Err(Error::UnknownDay(day));
+    }
+
+    let html = match file {
+        Some(path) => fs::read_to_string(path).map_err(|source| Error::ReadPage { path: path.to_owned(), source })?,
+        None => Client::from_environment()?.page(year, day)?,
+    };
+
+    let input_dir = input::input_dir().ok_or(Error::NoInputDir)?;
+    puzzle::archive(&puzzle::parse_puzzle(&html), &puzzle::puzzle_path(&input_dir, year, day), &day_dir)
+}
+
+fn archive(year: u16, workspace: &Path, found: u16, day: u8, file: Option<&Path>) -> ExitCode {
+    match archive_puzzle(year, workspace, found, day, file) {
+        Ok(archived) => {
+            println!("Saved the puzzle to `{}`", archived.markdown.display());
+            for path in archived.written {
+                println!("Saved an example to `{}`", path.display());
+            }
+            for path in archived.kept {
+                println!("Kept the example already at `{}`", path.display());
+            }
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn submit_part(year: u16, workspace: &Path, days: &[Day], found: u16, number: u8, part: Part, name: Option<&str>) -> Result<Submitted, Error> {
+    check_year(year, found)?;
+
+    let day = days
+        .iter()
+        .find(|day| day.day == number)
+        .ok_or(Error::UnknownDay(number))?;
+    let input_dir = input::input_dir().ok_or(Error::NoInputDir)?;
+    let input = match name {
+        Some(name) => input::named_input_path(&input_dir, year, number, name),
+        None => input::input_path(&input_dir, year, number),
+    };
+
+    // Each account has its own wait before it can answer again.
+    let throttle = Throttle::default_path().unwrap_or_else(|| input_dir.join("submit-after"));
+    let throttle = Throttle::new(match name {
+        Some(name) => throttle.with_file_name(format!("submit-after-{name}")),
+        None => throttle,
+    });
+
+    let submission = Submission { year, day, part, workspace, input: &input, name, throttle: &throttle };
+    submit::submit(&submission, Client::from_environment)
+}
+
+fn submit(year: u16, workspace: &Path, days: &[Day], found: u16, number: u8, part: Part, name: Option<&str>) -> ExitCode {
+    let (message, outcome) = match submit_part(year, workspace, days, found, number, part, name) {
+        Ok(Submitted::Known(answer)) => {
+            println!("Part {part} is already verified as {answer}");
+            return ExitCode::SUCCESS;
+        },
+        Ok(Submitted::Sent { answer, outcome }) => {
+            let message = match outcome.verdict {
+                Verdict::Correct => format!("{answer} is the right answer for part {part}, and has been saved"),
+                Verdict::TooHigh => format!("{answer} is too high"),
+                Verdict::TooLow => format!("{answer} is too low"),
+                Verdict::Wrong => format!("{answer} isn't the right answer"),
+                Verdict::RateLimited => format!("An answer was sent too recently, so {answer} wasn't checked"),
+                Verdict::AlreadySolved => format!("Part {part} has already been solved, so {answer} wasn't checked"),
+            };
+            (message, outcome)
+        },
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    match outcome.wait {
+        Some(wait) => println!("{message}; wait {} before trying again", submit::format_wait(wait)),
+        None => println!("{message}"),
+    }
+
+    if outcome.verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn load_leaderboard(year: u16, found: u16, id: Option<u64>, file: Option<&Path>) -> Result<Leaderboard, Error> {
+    check_year(year, found)?;
+
+    if let Some(path) = file {
+        let json = fs::read_to_string(path).map_err(|source| Error::ReadLeaderboard { path: path.to_owned(), source })?;
+        let leaderboard = leaderboard::parse_leaderboard(&json)?;
+        check_year(year, leaderboard.year)?;
+        return Ok(leaderboard);
+    }
+
+    let id = id.expect("clap to require an id without a file");
+    let cache = leaderboard::leaderboard_cache_path(year, id);
+    leaderboard::fetch_leaderboard(Client::from_environment, cache.as_deref(), year, id, SystemTime::now())
+}
+
+fn show_leaderboard(year: u16, found: u16, id: Option<u64>, file: Option<&Path>, day: Option<u8>, timings: bool) -> ExitCode {
+    let leaderboard = match load_leaderboard(year, found, id, file) {
+        Ok(leaderboard) => leaderboard,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let timings = timings
+        .then(input::input_dir)
+        .flatten()
+        .and_then(|input_dir| Timings::load(&progress::timings_path(&input_dir, year)).ok());
+
+    println!("{}", leaderboard::to_text(&leaderboard, day, timings.as_ref()));
+    ExitCode::SUCCESS
+}
+
+// Every year in the repository, or only this one when there is no repository
+// of years around it.
+fn load_progress(year: u16, workspace: &Path) -> Result<Vec<Progress>, Error> {
+    let mut years = progress::year_workspaces(workspace);
+    if years.is_empty() {
+        years.push((year, workspace.to_owned()));
+    }
+
+    let input_dir = input::input_dir();
+    years.iter().map(|(year, workspace)| Progress::load(*year, workspace, input_dir.as_deref())).collect()
+}
+
+fn write_readme(workspace: &Path, years: &[Progress]) -> Result<PathBuf, Error> {
+    let root = progress::repository_root(workspace).unwrap_or(workspace);
+    let path = root.join("README.md");
+    let readme = fs::read_to_string(&path).map_err(|source| Error::ReadReadme { path: path.clone(), source })?;
+    let readme = progress::update_readme(&readme, &progress::to_markdown(years));
+    fs::write(&path, readme).map_err(|source| Error::WriteReadme { path: path.clone(), source })?;
+    Ok(path)
+}
+
+fn show_progress(year: u16, workspace: &Path, readme: bool) -> ExitCode {
+    let years = match load_progress(year, workspace) {
+        Ok(years) => years,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let grids = years.iter().map(progress::to_grid).collect::<Vec<_>>();
+    println!("{}", grids.join("\n\n"));
+
+    if readme {
+        match write_readme(workspace, &years) {
+            Ok(path) => println!("\nUpdated the progress in `{}`", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Keep how long each verified part took, for `aoc progress`. Debug builds are
+// far slower than the solutions really are, so only release builds are kept.
+fn save_timings(year: u16, records: &[Record]) -> Result<(), Error> {
+    if cfg!(debug_assertions) {
+        return Ok(());
+    }
+
+    let path = progress::timings_path(&input::input_dir().ok_or(Error::NoInputDir)?, year);
+    let mut timings = Timings::load(&path)?;
+    if timings.update(records) {
+        timings.save(&path)?;
+    }
+    Ok(())
+}
+
+// The entry point for a year's `aoc` binary. `manifest_dir` is the binary's own
+// crate, which sits alongside each of the `day-XX` crates.
+pub fn main(year: u16, manifest_dir: &str, days: &[Day]) -> ExitCode {
+    let cli = Cli::parse();
+    let workspace = Path::new(manifest_dir).parent().expect("the runner to be inside a workspace");
+
+    match cli.command {
+        Some(Command::Fetch { year: found, day, name }) => fetch(year, found, day, name.as_deref()),
+        Some(Command::Puzzle { year: found, day, file }) => archive(year, workspace, found, day, file.as_deref()),
+        Some(Command::Submit { year: found, day, part, name }) => submit(year, workspace, days, found, day, part, name.as_deref()),
+        Some(Command::Leaderboard { year: found, id, file, day, timings }) => {
+            show_leaderboard(year, found, id, file.as_deref(), day, timings)
+        },
+        Some(Command::Progress { readme }) => show_progress(year, workspace, readme),
+        None => solve(year, workspace, days, &cli.args),
+    }
+}
+
+fn solve(year: u16, workspace: &Path, days: &[Day], args: &Args) -> ExitCode {
+    let records = match run(year, workspace, days, args) {
+        Ok(records) => records,
+        Err(error) => {
+            match args.format {
+                Format::Text => eprintln!("error: {error}"),
+                Format::Json => {
+                    let day = args.day.unwrap_or_default();
+                    let records = args
+                        .parts()
+                        .into_iter()
+                        .map(|part| Record::from_error(args.year.unwrap_or_default(), day, part, Status::Error, &error.to_string()))
+                        .collect::<Vec<_>>();
+                    println!("{}", report::to_json(&records));
+                },
+            }
+            return ExitCode::FAILURE;
+        },
+    };
+
+    // The timings are only a record, so failing to save them isn't fatal.
+    if let Err(error) = save_timings(year, &records) {
+        eprintln!("warning: {error}");
+    }
+
+    // A single day that couldn't even be run is reported as a plain error.
+    let errors = records.iter().filter(|record| record.status == Status::Error).collect::<Vec<_>>();
+    if args.format == Format::Text && args.day.is_some() && errors.len() == records.len() {
+        if let Some(error) = errors.first().and_then(|record| record.error.as_deref()) {
+            eprintln!("error: {error}");
+        }
+        return ExitCode::FAILURE;
+    }
+
+    match (args.format, args.day) {
+        (Format::Json, _) => println!("{}", report::to_json(&records)),
+        (Format::Text, Some(_)) => println!("{}", report::to_text(&records)),
+        (Format::Text, None) => println!("{}", report::to_table(&records)),
+    }
+
+    if records.iter().any(|record| record.status.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use super::{load_leaderboard, parse_day, parse_name, parse_part, Cli, Command};
+    use crate::{day::Part, error::Error, testing::TempDir};
+
+    #[test]
+    fn day_formats() {
+        assert_eq!(parse_day("5"), Ok(5));
+        assert_eq!(parse_day("05"), Ok(5));
+        assert_eq!(parse_day("day-05"), Ok(5));
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("day-").is_err());
+    }
+
+    #[test]
+    fn part_formats() {
+        assert_eq!(parse_part("2"), Ok(Part::Two));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn leaderboards_for_other_years() {
+        assert!(matches!(load_leaderboard(2023, 2024, Some(1), None), Err(Error::WrongYear { expected: 2023, found: 2024 })));
+
+        let dir = TempDir::new("cli-leaderboard");
+        let path = dir.join("leaderboard.json");
+        std::fs::write(&path, r#"{ "event": "2024", "owner_id": 1, "members": {} }"#).unwrap();
+        assert!(matches!(load_leaderboard(2023, 2023, None, Some(&path)), Err(Error::WrongYear { expected: 2023, found: 2024 })));
+        assert_eq!(load_leaderboard(2024, 2024, None, Some(&path)).unwrap().year, 2024);
+    }
+
+    #[test]
+    fn name_formats() {
+        assert_eq!(parse_name("alice-2"), Ok("alice-2".to_owned()));
+        assert!(parse_name("").is_err());
+        assert!(parse_name("../alice").is_err());
+    }
+
+    #[test]
+    fn commands() {
+        let cli = Cli::try_parse_from(["aoc", "fetch", "2023", "day-05"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Fetch { year: 2023, day: 5, name: None })));
+
+        let cli = Cli::try_parse_from(["aoc", "fetch", "2023", "5", "--name", "alice"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Fetch { name: Some(name), .. }) if name == "alice"));
+
+        let cli = Cli::try_parse_from(["aoc", "2023", "5", "--part", "1"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!((cli.args.year, cli.args.day), (Some(2023), Some(5)));
+
+        let cli = Cli::try_parse_from(["aoc", "submit", "2023", "5", "2"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Submit { year: 2023, day: 5, part: Part::Two, name: None })));
+
+        let cli = Cli::try_parse_from(["aoc", "leaderboard", "2023", "--file", "board.json", "--day", "5"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Leaderboard { year: 2023, id: None, file: Some(_), day: Some(5), .. })));
+        assert!(Cli::try_parse_from(["aoc", "leaderboard", "2023"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "progress", "--readme"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Progress { readme: true })));
+
+        assert!(Cli::try_parse_from(["aoc"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "2023"]).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::{env, fs, io, path::{Path, PathBuf}, time::Duration};
+use crate::{day::Part, input, Error};
+
+// The session cookie of a logged in user, which every personal request needs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+// Set this to point the client at another server, such as one in a test.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// The site asks that automated requests identify themselves.
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+// Where the session is kept when `AOC_SESSION` isn't set, such as
+// `~/.config/aoc/session` on Linux.
+pub fn session_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc").join("session"))
+}
+
+// The session token, from `AOC_SESSION` or else the session file.
+pub fn session() -> Result<String, Error> {
+    if let Some(session) = env::var(SESSION_VAR).ok().filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_owned());
+    }
+
+    let path = session_path().ok_or(Error::NoSession { path: None })?;
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+        Ok(_) => Err(Error::NoSession { path: Some(path) }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Err(Error::NoSession { path: Some(path) }),
+        Err(source) => Err(Error::ReadSession { path, source }),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            agent,
+        }
+    }
+
+    // A client for the real site, unless `AOC_BASE_URL` says otherwise.
+    pub fn from_environment() -> Result<Client, Error> {
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+
+        Ok(Client::new(&base_url, &session()?))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    // A page that needs the user's session, such as `/2023/day/5/input`.
+    fn get(&self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| request_error(&url, error))?;
+
+        response.into_string().map_err(|error| Error::Request { url, message: error.to_string() })
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    // The puzzle's page, which only includes part two for a user who has
+    // solved part one.
+    pub fn page(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    // A private leaderboard as JSON, which any of its members can see.
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<String, Error> {
+        self.get(&format!("/{year}/leaderboard/private/view/{id}.json"))
+    }
+
+    // Post an answer, returning the page that says what the site made of it.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, Error> {
+        let url = self.url(&format!("/{year}/day/{day}/answer"));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.number().to_string()), ("answer", answer)])
+            .map_err(|error| request_error(&url, error))?;
+
+        response.into_string().map_err(|error| Error::Request { url, message: error.to_string() })
+    }
+}
+
+fn request_error(url: &str, error: ureq::Error) -> Error {
+    let message = match error {
+        // The site answers a bad session with a 400, and a day that hasn't been
+        // released yet with a 404.
+        ureq::Error::Status(400, _) => "the session was rejected, it may have expired".to_owned(),
+        ureq::Error::Status(404, _) => "the puzzle isn't available yet".to_owned(),
+        ureq::Error::Status(code, response) => format!("the server responded with {code} {}", response.status_text()),
+        ureq::Error::Transport(transport) => transport.to_string(),
+    };
+
+    Error::Request { url: url.to_owned(), message }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    // The input was already in the input directory, so it wasn't asked for.
+    Cached(PathBuf),
+}
+
+// Download a day's input into the input directory, unless it is already there.
+// Each input never changes, so there's no reason to ask for it twice. A `name`
+// saves it as another user's input, for when the session is theirs.
+pub fn fetch_input(
+    client: impl FnOnce() -> Result<Client, Error>,
+    input_dir: &Path,
+    year: u16,
+    day: u8,
+    name: Option<&str>,
+) -> Result<Fetched, Error> {
+    let path = match name {
+        Some(name) => input::named_input_path(input_dir, year, day, name),
+        None => input::input_path(input_dir, year, day),
+    };
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let text = client()?.input(year, day)?;
+
+    let write = || {
+        fs::create_dir_all(path.parent().expect("inputs to be inside a year's directory"))?;
+        fs::write(&path, &text)
+    };
+    write().map_err(|source| Error::WriteInput { path: path.clone(), source })?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{fetch_input, Client, Fetched};
+    use crate::{testing::{serve, TempDir}, Error};
+
+    fn input_dir(name: &str) -> TempDir {
+        TempDir::new(&format!("client-{name}"))
+    }
+
+    #[test]
+    fn downloads_then_caches() {
+        let (url, server) = serve("200 OK", "1 2 3\n");
+        let dir = input_dir("cache");
+
+        let fetched = fetch_input(|| Ok(Client::new(&url, "secret")), &dir, 2023, 5, None).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("2023/05.txt")));
+        assert_eq!(fs::read_to_string(dir.join("2023/05.txt")).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.head.starts_with("GET /2023/day/5/input "));
+        assert!(request.head.to_lowercase().contains("cookie: session=secret"));
+
+        // The server has gone, so this would fail if it asked again.
+        let fetched = fetch_input(|| panic!("the client to not be needed"), &dir, 2023, 5, None).unwrap();
+        assert_eq!(fetched, Fetched::Cached(dir.join("2023/05.txt")));
+
+        // Another user's input is kept apart from our own.
+        let (url, server) = serve("200 OK", "4 5 6\n");
+        let fetched = fetch_input(|| Ok(Client::new(&url, "theirs")), &dir, 2023, 5, Some("alice")).unwrap();
+        server.join().unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("2023/05/alice.txt")));
+        assert_eq!(fs::read_to_string(dir.join("2023/05.txt")).unwrap(), "1 2 3\n");
+    }
+
+    #[test]
+    fn explains_failures() {
+        let (url, server) = serve("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!");
+        let dir = input_dir("missing");
+
+        let error = fetch_input(|| Ok(Client::new(&url, "secret")), &dir, 2023, 25, None).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(error, Error::Request { .. }));
+        assert!(error.to_string().contains("isn't available yet"));
+        assert!(!dir.join("2023/25.txt").exists());
+    }
+}
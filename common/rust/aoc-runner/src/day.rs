@@ -0,0 +1,164 @@
+use std::{fmt, time::{Duration, Instant}};
+use crate::Solution;
+
+type Run = fn(&str, &[Part]) -> Execution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn new(part: u8) -> Option<Part> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+// Each day exposes its answers as whatever type suits it, so the runner only
+// ever sees them once they have been formatted.
+#[derive(Debug)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug)]
+pub struct Execution {
+    pub parse: Duration,
+    pub parts: Vec<Solved>,
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Execution {
+    let start = Instant::now();
+    let input = S::parse(input);
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            // Formatting the answer isn't part of solving it, so it isn't timed.
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            };
+            let duration = start.elapsed();
+
+            Solved { part, answer: answer.to_string(), duration }
+        })
+        .collect();
+
+    Execution { parse, parts }
+}
+
+// A single puzzle day that the runner is able to dispatch to.
+pub struct Day {
+    pub day: u8,
+    run: Run,
+}
+
+impl Day {
+    pub fn new<S: Solution>(day: u8) -> Day {
+        Day { day, run: run::<S> }
+    }
+
+    // Parse the input once, then solve each of the given parts with it.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Execution {
+        (self.run)(input, parts)
+    }
+
+    // The name of the crate for this day, such as `day-05`.
+    pub fn name(&self) -> String {
+        format!("day-{:02}", self.day)
+    }
+}
+
+// The day of a crate's name, such as `5` for `day-05`.
+pub(crate) fn parse_name(name: &str) -> Option<u8> {
+    name.strip_prefix("day-").and_then(|day| day.parse().ok())
+}
+
+// Register a day crate by the `Puzzle` that it exposes.
+#[macro_export]
+macro_rules! day {
+    ($day:literal, $krate:ident) => {
+        $crate::Day::new::<$krate::Puzzle>($day)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_name, Day, Part};
+    use crate::Solution;
+
+    struct Letters;
+
+    impl Solution for Letters {
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> String {
+            input.trim().to_owned()
+        }
+
+        fn part1(input: &String) -> String {
+            input.len().to_string()
+        }
+
+        fn part2(input: &String) -> String {
+            input.to_uppercase()
+        }
+    }
+
+    #[test]
+    fn dispatches_to_parts() {
+        let answers = |parts: &[Part]| {
+            Day::new::<Letters>(3)
+                .run(" abc ", parts)
+                .parts
+                .into_iter()
+                .map(|solved| (solved.part, solved.answer))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(answers(&Part::ALL), vec![(Part::One, "3".to_owned()), (Part::Two, "ABC".to_owned())]);
+        assert_eq!(answers(&[Part::Two]), vec![(Part::Two, "ABC".to_owned())]);
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(parse_name("day-05"), Some(5));
+        assert_eq!(parse_name("day-"), None);
+        assert_eq!(parse_name("aoc"), None);
+    }
+
+    #[test]
+    fn name_is_padded() {
+        let day = Day::new::<Letters>(5);
+        assert_eq!(day.name(), "day-05");
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fmt, io, path::PathBuf, time::Duration};
+use crate::answers::Mistake;
+
+#[derive(Debug)]
+pub enum Error {
+    WrongYear { expected: u16, found: u16 },
+    UnknownDay(u8),
+    UnregisteredDay(u8),
+    ReadInput { path: PathBuf, source: io::Error },
+    ReadStdin(io::Error),
+    NoStdin,
+    MissingExample { name: String, path: PathBuf },
+    NoInput { default: PathBuf },
+    NoInputDir,
+    ReadAnswers { path: PathBuf, source: io::Error },
+    InvalidAnswers { path: PathBuf, message: String },
+    NoSession { path: Option<PathBuf> },
+    ReadSession { path: PathBuf, source: io::Error },
+    Request { url: String, message: String },
+    WriteInput { path: PathBuf, source: io::Error },
+    WriteAnswers { path: PathBuf, source: io::Error },
+    ReadPage { path: PathBuf, source: io::Error },
+    WritePuzzle { path: PathBuf, source: io::Error },
+    Throttled { wait: Duration },
+    KnownAnswer { answer: String, expected: String },
+    KnownMistake { answer: String, mistake: Mistake },
+    ReadTimings { path: PathBuf, source: io::Error },
+    InvalidTimings { path: PathBuf, message: String },
+    WriteTimings { path: PathBuf, source: io::Error },
+    ReadReadme { path: PathBuf, source: io::Error },
+    WriteReadme { path: PathBuf, source: io::Error },
+    ReadLeaderboard { path: PathBuf, source: io::Error },
+    InvalidLeaderboard(String),
+    Panic(String),
+    UnexpectedResponse(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::WrongYear { expected, found } => write!(f, "this runner only has solutions for {expected}, not {found}"),
+            Error::UnknownDay(day) => write!(f, "there is no solution for day {day}"),
+            Error::UnregisteredDay(day) => write!(f, "day-{day:02} exists, but isn't registered with the runner"),
+            Error::ReadInput { path, source } => write!(f, "could not read input `{}`: {source}", path.display()),
+            Error::ReadStdin(source) => write!(f, "could not read input from stdin: {source}"),
+            Error::NoStdin => write!(f, "the input can only be read from stdin when running a single day"),
+            Error::MissingExample { name, path } => write!(f, "there is no example called `{name}`, expected `{}`", path.display()),
+            Error::NoInput { default } => write!(
+                f,
+                "no input was found; pass `--input <path>`, set `{}`, pipe it on stdin or save it to `{}`",
+                crate::input::INPUT_VAR,
+                default.display(),
+            ),
+            Error::NoInputDir => write!(f, "could not find a directory for the inputs; set `{}`", crate::input::INPUT_DIR_VAR),
+            Error::ReadAnswers { path, source } => write!(f, "could not read answers `{}`: {source}", path.display()),
+            Error::InvalidAnswers { path, message } => write!(f, "could not parse answers `{}`: {}", path.display(), message.trim_end()),
+            Error::NoSession { path: Some(path) } => write!(
+                f,
+                "no session was found; set `{}` or save it to `{}`",
+                crate::client::SESSION_VAR,
+                path.display(),
+            ),
+            Error::NoSession { path: None } => write!(f, "no session was found; set `{}`", crate::client::SESSION_VAR),
+            Error::ReadSession { path, source } => write!(f, "could not read session `{}`: {source}", path.display()),
+            Error::Request { url, message } => write!(f, "could not get `{url}`: {message}"),
+            Error::WriteInput { path, source } => write!(f, "could not save input `{}`: {source}", path.display()),
+            Error::WriteAnswers { path, source } => write!(f, "could not save answers `{}`: {source}", path.display()),
+            Error::ReadPage { path, source } => write!(f, "could not read page `{}`: {source}", path.display()),
+            Error::WritePuzzle { path, source } => write!(f, "could not save puzzle `{}`: {source}", path.display()),
+            Error::Throttled { wait } => write!(f, "answers can't be submitted for another {}", crate::submit::format_wait(*wait)),
+            Error::KnownAnswer { answer, expected } => {
+                write!(f, "the answer {answer} doesn't match the verified answer {expected}, so it wasn't submitted")
+            },
+            Error::KnownMistake { answer, mistake } => write!(f, "the answer {answer} is {mistake}, so it wasn't submitted"),
+            Error::ReadTimings { path, source } => write!(f, "could not read timings `{}`: {source}", path.display()),
+            Error::InvalidTimings { path, message } => write!(f, "could not parse timings `{}`: {}", path.display(), message.trim_end()),
+            Error::WriteTimings { path, source } => write!(f, "could not save timings `{}`: {source}", path.display()),
+            Error::ReadReadme { path, source } => write!(f, "could not read `{}`: {source}", path.display()),
+            Error::WriteReadme { path, source } => write!(f, "could not save `{}`: {source}", path.display()),
+            Error::ReadLeaderboard { path, source } => write!(f, "could not read leaderboard `{}`: {source}", path.display()),
+            Error::InvalidLeaderboard(message) => {
+                write!(f, "could not parse the leaderboard, the session may have expired or not be a member: {message}")
+            },
+            Error::Panic(message) => write!(f, "the solution panicked: {message}"),
+            Error::UnexpectedResponse(text) => write!(f, "the response wasn't understood: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ReadInput { source, .. }
+            | Error::ReadStdin(source)
+            | Error::ReadAnswers { source, .. }
+            | Error::ReadSession { source, .. }
+            | Error::WriteInput { source, .. }
+            | Error::WriteAnswers { source, .. }
+            | Error::ReadPage { source, .. }
+            | Error::WritePuzzle { source, .. }
+            | Error::ReadTimings { source, .. }
+            | Error::WriteTimings { source, .. }
+            | Error::ReadReadme { source, .. }
+            | Error::WriteReadme { source, .. }
+            | Error::ReadLeaderboard { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
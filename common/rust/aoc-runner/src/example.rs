@@ -0,0 +1,174 @@
+use std::{fs, path::{Path, PathBuf}};
+use crate::{day::{self, Day, Part}, Solution};
+
+// An example from a puzzle, along with the answers it should give. These are
+// written as a header at the top of the file, ended by a `---` line:
+//
+//     part1: 142
+//     part2: 281
+//     ---
+//     1abc2
+//
+// A part that isn't given isn't checked, as some examples are only for one of
+// the parts. Nor is a part whose answer is left empty, such as `part2:` while
+// the part isn't solved yet. A file without a header is only the example
+// itself.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub answers: [Option<String>; 2],
+    pub input: String,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers[usize::from(part.number() - 1)].as_deref()
+    }
+}
+
+fn header_line(line: &str) -> Option<(Part, &str)> {
+    let (key, answer) = line.split_once(':')?;
+    let part = key.trim().strip_prefix("part")?.parse().ok().and_then(Part::new)?;
+    Some((part, answer.trim()))
+}
+
+pub fn parse_example(text: &str) -> Example {
+    let mut answers = [None, None];
+    let mut lines = text.split_inclusive('\n');
+    let mut consumed = 0;
+
+    for line in lines.by_ref() {
+        consumed += line.len();
+        if line.trim_end() == "---" {
+            return Example { answers, input: text[consumed..].to_owned() };
+        }
+
+        match header_line(line) {
+            Some((part, answer)) if !answer.is_empty() => answers[usize::from(part.number() - 1)] = Some(answer.to_owned()),
+            Some(_) => {},
+            None => break,
+        }
+    }
+
+    Example { answers: [None, None], input: text.to_owned() }
+}
+
+// Every example in a day's `examples` directory, in order of their names, so
+// that `2.txt` comes before `10.txt`.
+pub fn examples(day_dir: &Path) -> Vec<(PathBuf, Example)> {
+    let Ok(entries) = fs::read_dir(day_dir.join("examples")) else {
+        return Vec::new();
+    };
+
+    let mut paths = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect::<Vec<_>>();
+    paths.sort_by_key(|path| {
+        let name = path.file_stem().and_then(|name| name.to_str()).unwrap_or_default().to_owned();
+        (name.parse::<u32>().ok(), name)
+    });
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = fs::read_to_string(&path).unwrap_or_else(|error| panic!("could not read `{}`: {error}", path.display()));
+            (path, parse_example(&text))
+        })
+        .collect()
+}
+
+// The example tests for a day, which solve every example in its `examples`
+// directory and check each answer in their headers. `manifest_dir` is the day's
+// crate, as given by `env!("CARGO_MANIFEST_DIR")`.
+pub fn check_examples<S: Solution>(manifest_dir: &str) {
+    let day_dir = Path::new(manifest_dir);
+    let name = day_dir.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let day = Day::new::<S>(day::parse_name(name).unwrap_or_default());
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (path, example) in examples(day_dir) {
+        let parts = Part::ALL.into_iter().filter(|&part| example.answer(part).is_some()).collect::<Vec<_>>();
+        if parts.is_empty() {
+            eprintln!("`{}` has no answers to check", path.display());
+            continue;
+        }
+
+        for solved in day.run(&example.input, &parts).parts {
+            let expected = example.answer(solved.part).unwrap_or_default();
+            if solved.answer != expected {
+                let file = path.file_name().unwrap_or_default().to_string_lossy();
+                failures.push(format!("{file} part {}: expected {expected}, got {}", solved.part, solved.answer));
+            }
+            checked += 1;
+        }
+    }
+
+    if checked == 0 {
+        eprintln!("{name} has no examples with answers to check");
+    }
+    assert!(failures.is_empty(), "{} of {checked} examples failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, panic};
+    use super::{check_examples, examples, parse_example, Example};
+    use crate::{day::Part, testing::TempDir, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input.lines().map(|line| line.parse().expect("a number")).collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u32>) -> u32 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn parses_headers() {
+        assert_eq!(
+            parse_example("part1: 142\npart2: ABC\n---\n1abc2\n"),
+            Example { answers: [Some("142".to_owned()), Some("ABC".to_owned())], input: "1abc2\n".to_owned() },
+        );
+        assert_eq!(parse_example("part2: 281\n---\none\n").answer(Part::One), None);
+        assert_eq!(parse_example("part1:\n---\none\n").answer(Part::One), None);
+
+        // Without a header, it is all input.
+        assert_eq!(parse_example("#.#\n---\n").input, "#.#\n---\n");
+        assert_eq!(parse_example("part1: 3\n1\n").input, "part1: 3\n1\n");
+    }
+
+    #[test]
+    fn checks_every_example() {
+        let scratch = TempDir::new("example");
+        let dir = scratch.join("day-04");
+        fs::create_dir_all(dir.join("examples")).unwrap();
+        fs::write(dir.join("examples/10.txt"), "part2: 24\n---\n2\n3\n4\n").unwrap();
+        fs::write(dir.join("examples/2.txt"), "part1: 5\npart2: 6\n---\n2\n3\n").unwrap();
+        // A part that isn't solved yet is left empty rather than failing.
+        fs::write(dir.join("examples/5.txt"), "part1: 9\npart2:\n---\n4\n5\n").unwrap();
+        fs::write(dir.join("examples/notes.md"), "not an example").unwrap();
+
+        let names = examples(&dir).into_iter().map(|(path, _)| path.file_name().unwrap().to_owned()).collect::<Vec<_>>();
+        assert_eq!(names, ["2.txt", "5.txt", "10.txt"]);
+        check_examples::<Sum>(dir.to_str().unwrap());
+
+        fs::write(dir.join("examples/3.txt"), "part1: 7\n---\n1\n").unwrap();
+        let error = panic::catch_unwind(|| check_examples::<Sum>(dir.to_str().unwrap())).unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(message.contains("1 of 5 examples failed"));
+        assert!(message.contains("3.txt part one: expected 7, got 1"));
+    }
+}
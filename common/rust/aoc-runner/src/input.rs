@@ -0,0 +1,290 @@
+use std::{env, fmt, fs, io::{self, IsTerminal, Read}, path::{Path, PathBuf}};
+use crate::{day::Part, example, Error};
+
+// Set this to a file to use it as the input, instead of the day's own input.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+// Set this to the directory that holds everyone's own inputs, which are kept
+// out of the repository as `<year>/<day>.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// Where the inputs are kept when `AOC_INPUT_DIR` isn't set, such as
+// `~/.local/share/aoc/inputs` on Linux.
+pub fn input_dir() -> Option<PathBuf> {
+    match env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => dirs::data_dir().map(|dir| dir.join("aoc").join("inputs")),
+    }
+}
+
+// The day's own input within the input directory, such as `2023/05.txt`.
+pub fn input_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("{day:02}.txt"))
+}
+
+// Another user's input for the day, kept beside the day's own input, such as
+// `2023/05/alice.txt`.
+pub fn named_input_path(input_dir: &Path, year: u16, day: u8, name: &str) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("{day:02}")).join(format!("{name}.txt"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+// Every other user's input for the day, in order of their names.
+pub fn named_inputs(input_dir: &Path, year: u16, day: u8) -> Vec<NamedInput> {
+    let Ok(entries) = fs::read_dir(input_dir.join(year.to_string()).join(format!("{day:02}"))) else {
+        return Vec::new();
+    };
+
+    let mut inputs = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_owned();
+            Some(NamedInput { name, path })
+        })
+        .collect::<Vec<_>>();
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    inputs
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub source: Source,
+    pub text: String,
+    // The answers given in an example's header, which it can be checked against.
+    pub answers: [Option<String>; 2],
+}
+
+impl Input {
+    fn new(source: Source, text: String) -> Input {
+        Input { source, text, answers: [None, None] }
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers[usize::from(part.number() - 1)].as_deref()
+    }
+}
+
+// Everything that can decide where a day's input comes from.
+#[derive(Debug)]
+pub struct Request<'a> {
+    // An explicit path given on the command line, where `-` means stdin.
+    pub path: Option<&'a Path>,
+    // The name of a file in the day's `examples` directory, without `.txt`.
+    pub example: Option<&'a str>,
+    // The day's crate, which holds its `examples`.
+    pub day_dir: &'a Path,
+    // The day's own input, which is used when nothing else is given.
+    pub default: &'a Path,
+    // Other users' inputs, which are run as well whenever the day's own input
+    // is.
+    pub named: &'a [NamedInput],
+    // Whether `AOC_INPUT` and piped stdin can be used. These only make sense
+    // when running a single day.
+    pub from_environment: bool,
+}
+
+impl<'a> Request<'a> {
+    pub fn new(day_dir: &'a Path, default: &'a Path) -> Request<'a> {
+        Request { path: None, example: None, day_dir, default, named: &[], from_environment: false }
+    }
+
+    pub fn example_path(&self, name: &str) -> PathBuf {
+        self.day_dir.join("examples").join(format!("{name}.txt"))
+    }
+
+    // Find the input, in order of the most to the least explicit.
+    //
+    // Stdin is only used when something has been piped into it, otherwise we
+    // would block waiting on a terminal, unless it was asked for with
+    // `--input -`, when the input can be typed in.
+    pub fn resolve(&self) -> Result<Input, Error> {
+        if !self.from_environment {
+            return self.resolve_with(None, |_| Ok(None));
+        }
+
+        let stdin = |explicit: bool| {
+            let mut stdin = io::stdin();
+            if stdin.is_terminal() && !explicit {
+                return Ok(None);
+            }
+
+            let mut text = String::new();
+            stdin.read_to_string(&mut text).map_err(Error::ReadStdin)?;
+            Ok(Some(text))
+        };
+
+        self.resolve_with(env::var_os(INPUT_VAR).map(PathBuf::from), stdin)
+    }
+
+    fn resolve_with(
+        &self,
+        env_path: Option<PathBuf>,
+        stdin: impl FnOnce(bool) -> Result<Option<String>, Error>,
+    ) -> Result<Input, Error> {
+        if let Some(path) = self.path {
+            if path == Path::new("-") {
+                let text = stdin(true)?.ok_or(Error::NoStdin)?;
+                return Ok(Input::new(Source::Stdin, text));
+            }
+            return read_file(path.to_owned());
+        }
+
+        if let Some(name) = self.example {
+            let path = self.example_path(name);
+            if !path.is_file() {
+                return Err(Error::MissingExample { name: name.to_owned(), path });
+            }
+            let input = read_file(path)?;
+            let example = example::parse_example(&input.text);
+            return Ok(Input { text: example.input, answers: example.answers, ..input });
+        }
+
+        if let Some(path) = env_path.filter(|path| !path.as_os_str().is_empty()) {
+            return read_file(path);
+        }
+
+        // An empty pipe, such as `/dev/null` in CI, shouldn't hide the input.
+        if let Some(text) = stdin(false)?.filter(|text| !text.is_empty()) {
+            return Ok(Input::new(Source::Stdin, text));
+        }
+
+        if self.default.is_file() {
+            return read_file(self.default.to_owned());
+        }
+
+        Err(Error::NoInput { default: self.default.to_owned() })
+    }
+}
+
+pub(crate) fn read_file(path: PathBuf) -> Result<Input, Error> {
+    match fs::read_to_string(&path) {
+        Ok(text) => Ok(Input::new(Source::File(path), text)),
+        Err(source) => Err(Error::ReadInput { path, source }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::{Path, PathBuf}};
+    use super::{input_path, Request, Source};
+    use crate::{day::Part, testing::TempDir, Error};
+
+    fn day_dir(name: &str) -> TempDir {
+        let dir = TempDir::new(&format!("input-{name}"));
+        fs::create_dir_all(dir.join("inputs/2023")).unwrap();
+        fs::create_dir_all(dir.join("examples")).unwrap();
+        dir
+    }
+
+    fn default_path(dir: &Path) -> PathBuf {
+        input_path(&dir.join("inputs"), 2023, 5)
+    }
+
+    fn no_stdin(_: bool) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    #[test]
+    fn falls_back_to_day_input() {
+        let dir = day_dir("default");
+        let default = default_path(&dir);
+        fs::write(&default, "default").unwrap();
+
+        let request = Request::new(&dir, &default);
+        let input = request.resolve_with(None, no_stdin).unwrap();
+        assert_eq!(input.text, "default");
+        assert_eq!(input.source, Source::File(dir.join("inputs/2023/05.txt")));
+    }
+
+    #[test]
+    fn explicit_sources_win() {
+        let dir = day_dir("explicit");
+        let default = default_path(&dir);
+        fs::write(&default, "default").unwrap();
+        fs::write(dir.join("examples/small.txt"), "example").unwrap();
+        fs::write(dir.join("other.txt"), "other").unwrap();
+
+        let request = Request::new(&dir, &default);
+        let piped = |_| Ok(Some("piped".to_owned()));
+        assert_eq!(request.resolve_with(None, piped).unwrap().text, "piped");
+        assert_eq!(request.resolve_with(Some(dir.join("other.txt")), piped).unwrap().text, "other");
+
+        let request = Request { example: Some("small"), ..Request::new(&dir, &default) };
+        assert_eq!(request.resolve_with(Some(dir.join("other.txt")), piped).unwrap().text, "example");
+
+        fs::write(dir.join("examples/answered.txt"), "part2: 46\n---\nexample\n").unwrap();
+        let request = Request { example: Some("answered"), ..Request::new(&dir, &default) };
+        let input = request.resolve_with(None, piped).unwrap();
+        assert_eq!((input.text.as_str(), input.answer(Part::Two)), ("example\n", Some("46")));
+
+        let path = dir.join("other.txt");
+        let request = Request { path: Some(&path), example: Some("small"), ..Request::new(&dir, &default) };
+        assert_eq!(request.resolve_with(None, piped).unwrap().text, "other");
+
+        let request = Request { path: Some(Path::new("-")), ..Request::new(&dir, &default) };
+        assert_eq!(request.resolve_with(None, piped).unwrap().source, Source::Stdin);
+    }
+
+    #[test]
+    fn explicit_stdin_is_read_from_a_terminal() {
+        let dir = day_dir("terminal");
+        let default = default_path(&dir);
+        fs::write(&default, "default").unwrap();
+
+        // Stands in for a terminal, which is only read when asked for.
+        let terminal = |explicit: bool| Ok(explicit.then(|| "typed".to_owned()));
+        assert_eq!(Request::new(&dir, &default).resolve_with(None, terminal).unwrap().text, "default");
+
+        let request = Request { path: Some(Path::new("-")), ..Request::new(&dir, &default) };
+        assert_eq!(request.resolve_with(None, terminal).unwrap().text, "typed");
+        assert!(matches!(request.resolve_with(None, no_stdin), Err(Error::NoStdin)));
+    }
+
+    #[test]
+    fn empty_stdin_is_ignored() {
+        let dir = day_dir("empty");
+        let default = default_path(&dir);
+        fs::write(&default, "default").unwrap();
+
+        let request = Request::new(&dir, &default);
+        let input = request.resolve_with(None, |_| Ok(Some(String::new()))).unwrap();
+        assert_eq!(input.text, "default");
+    }
+
+    #[test]
+    fn missing_input_is_an_error() {
+        let dir = day_dir("missing");
+        let default = default_path(&dir);
+
+        let request = Request::new(&dir, &default);
+        let error = request.resolve_with(None, no_stdin).unwrap_err();
+        assert!(matches!(error, Error::NoInput { .. }));
+        assert!(error.to_string().contains("AOC_INPUT"));
+
+        let request = Request { example: Some("nope"), ..Request::new(&dir, &default) };
+        let error = request.resolve_with(None, no_stdin).unwrap_err();
+        assert!(matches!(error, Error::MissingExample { .. }));
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+use serde::Deserialize;
+use crate::{client::Client, day::Part, progress::Timings, report, timing::format_duration, Error};
+
+// The site asks that a leaderboard is downloaded at most once every 15 minutes.
+pub const LEADERBOARD_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+struct RawStar {
+    get_star_ts: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawMember {
+    id: u64,
+    name: Option<String>,
+    #[serde(default)]
+    local_score: u64,
+    #[serde(default)]
+    stars: u32,
+    // Each day's parts by number, such as `"1": { "2": { ... } }`.
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, RawStar>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawLeaderboard {
+    event: String,
+    members: BTreeMap<String, RawMember>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u32,
+    // How long after the puzzle unlocked each part was solved.
+    pub solved: BTreeMap<(u8, Part), Duration>,
+}
+
+impl Member {
+    pub fn time(&self, day: u8, part: Part) -> Option<Duration> {
+        self.solved.get(&(day, part)).copied()
+    }
+
+    // How long part two took, once part one was solved.
+    pub fn delta(&self, day: u8) -> Option<Duration> {
+        let one = self.time(day, Part::One)?;
+        Some(self.time(day, Part::Two)?.saturating_sub(one))
+    }
+}
+
+// A private leaderboard, with its members in order of their local score.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    // Every day that someone has a star for, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self.members.iter().flat_map(|member| member.solved.keys().map(|&(day, _)| day)).collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+// The days since 1970-01-01 of a date, from Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Puzzles unlock at midnight in New York, which is five hours behind UTC in
+// December.
+fn unlocked_at(year: u16, day: u8) -> i64 {
+    days_from_civil(year.into(), 12, day.into()) * 86_400 + 5 * 3_600
+}
+
+pub fn parse_leaderboard(json: &str) -> Result<Leaderboard, Error> {
+    let raw = serde_json::from_str::<RawLeaderboard>(json).map_err(|error| Error::InvalidLeaderboard(error.to_string()))?;
+    let year = raw
+        .event
+        .parse::<u16>()
+        .map_err(|_| Error::InvalidLeaderboard(format!("`{}` is not a year", raw.event)))?;
+
+    let mut members = raw
+        .members
+        .into_values()
+        .map(|member| {
+            let mut solved = BTreeMap::new();
+            for (day, parts) in &member.completion_day_level {
+                let Ok(day) = day.parse::<u8>() else {
+                    continue;
+                };
+                for (part, star) in parts {
+                    let Some(part) = part.parse().ok().and_then(Part::new) else {
+                        continue;
+                    };
+                    let seconds = (star.get_star_ts - unlocked_at(year, day)).max(0);
+                    solved.insert((day, part), Duration::from_secs(seconds.unsigned_abs()));
+                }
+            }
+
+            Member {
+                id: member.id,
+                name: member.name.unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                local_score: member.local_score,
+                stars: member.stars,
+                solved,
+            }
+        })
+        .collect::<Vec<_>>();
+    members.sort_by(|a, b| b.local_score.cmp(&a.local_score).then(b.stars.cmp(&a.stars)).then(a.name.cmp(&b.name)));
+
+    Ok(Leaderboard { year, members })
+}
+
+// Where a downloaded leaderboard is kept, such as
+// `~/.cache/aoc/leaderboards/2023-12345.json` on Linux.
+pub fn leaderboard_cache_path(year: u16, id: u64) -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("aoc").join("leaderboards").join(format!("{year}-{id}.json")))
+}
+
+// Download a private leaderboard, unless the cached copy is recent enough. Only
+// a leaderboard that could be read is cached, so a rejected session isn't kept.
+pub fn fetch_leaderboard(
+    client: impl FnOnce() -> Result<Client, Error>,
+    cache: Option<&Path>,
+    year: u16,
+    id: u64,
+    now: SystemTime,
+) -> Result<Leaderboard, Error> {
+    if let Some(cache) = cache {
+        let age = fs::metadata(cache)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok());
+        let cached = fs::read_to_string(cache).ok().filter(|_| age.is_some_and(|age| age < LEADERBOARD_REFRESH));
+        if let Some(leaderboard) = cached.and_then(|json| parse_leaderboard(&json).ok()) {
+            return Ok(leaderboard);
+        }
+    }
+
+    let json = client()?.leaderboard(year, id)?;
+    let leaderboard = parse_leaderboard(&json)?;
+
+    // Failing to cache it only means asking again next time.
+    if let Some(cache) = cache {
+        if let Some(dir) = cache.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(cache, json);
+    }
+
+    Ok(leaderboard)
+}
+
+// How long after the puzzle unlocked, such as `01:02:03`, with any whole days
+// in front, as in `2d 01:02:03`.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let time = format!("{:02}:{:02}:{:02}", seconds / 3_600, seconds / 60 % 60, seconds % 60);
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+fn elapsed(elapsed: Option<Duration>) -> String {
+    elapsed.map(format_elapsed).unwrap_or_default()
+}
+
+// The members' scores, then how long each of them took for every day that has
+// been solved, with the quickest first. Given our own timings, each day also
+// shows how long our solutions take.
+pub fn to_text(leaderboard: &Leaderboard, day: Option<u8>, timings: Option<&Timings>) -> String {
+    let mut lines = vec![format!("{} private leaderboard, {} members", leaderboard.year, leaderboard.members.len()), String::new()];
+
+    let mut rows = vec![["Rank", "Member", "Score", "Stars"].map(str::to_owned).to_vec()];
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        rows.push(vec![(rank + 1).to_string(), member.name.clone(), member.local_score.to_string(), member.stars.to_string()]);
+    }
+    lines.extend(report::align(&rows));
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+    for day in days {
+        lines.push(String::new());
+        lines.push(format!("Day {day:02}"));
+
+        let mut members = leaderboard.members.iter().filter(|member| member.time(day, Part::One).is_some()).collect::<Vec<_>>();
+        if members.is_empty() {
+            lines.push("No one has solved it yet".to_owned());
+        } else {
+            // Those with both stars come first, in the order they got them.
+            members.sort_by_key(|member| (member.time(day, Part::Two).is_none(), member.time(day, Part::Two), member.time(day, Part::One)));
+
+            let mut rows = vec![["Member", "Part 1", "Part 2", "Delta"].map(str::to_owned).to_vec()];
+            for member in members {
+                rows.push(vec![
+                    member.name.clone(),
+                    elapsed(member.time(day, Part::One)),
+                    elapsed(member.time(day, Part::Two)),
+                    elapsed(member.delta(day)),
+                ]);
+            }
+            lines.extend(report::align(&rows));
+        }
+
+        let ours = Part::ALL
+            .into_iter()
+            .filter_map(|part| Some(format!("part {} {}", part.number(), format_duration(timings?.get(day, part)?))))
+            .collect::<Vec<_>>();
+        if !ours.is_empty() {
+            lines.push(format!("Our solutions: {}", ours.join(", ")));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::{Duration, SystemTime}};
+    use super::{fetch_leaderboard, format_elapsed, parse_leaderboard, to_text, unlocked_at};
+    use crate::{client::Client, day::Part, progress::Timings, testing::{serve, TempDir}};
+
+    // Day 1 of 2023 unlocked at 1701406800.
+    const JSON: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "local_score": 10, "stars": 3, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1701407100, "star_index": 1 }, "2": { "get_star_ts": 1701407700, "star_index": 2 } },
+                    "2": { "1": { "get_star_ts": 1701500000, "star_index": 3 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "local_score": 12, "stars": 2, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1701406860, "star_index": 4 }, "2": { "get_star_ts": 1701493200, "star_index": 5 } }
+                }
+            },
+            "3": { "id": 3, "name": "Carol", "local_score": 0, "stars": 0, "completion_day_level": {} }
+        }
+    }"#;
+
+    #[test]
+    fn unlocks_at_midnight_in_new_york() {
+        assert_eq!(unlocked_at(2023, 1), 1_701_406_800);
+        assert_eq!(unlocked_at(2024, 25), 1_735_102_800);
+    }
+
+    #[test]
+    fn formats_elapsed_times() {
+        assert_eq!(format_elapsed(Duration::from_secs(5 * 60 + 3)), "00:05:03");
+        assert_eq!(format_elapsed(Duration::from_secs(2 * 86_400 + 3_600)), "2d 01:00:00");
+    }
+
+    #[test]
+    fn reads_members() {
+        let leaderboard = parse_leaderboard(JSON).unwrap();
+        assert_eq!(leaderboard.year, 2023);
+        let names = leaderboard.members.iter().map(|member| member.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["(anonymous user #2)", "Alice", "Carol"]);
+
+        let alice = &leaderboard.members[1];
+        assert_eq!(alice.time(1, Part::One), Some(Duration::from_secs(300)));
+        assert_eq!(alice.delta(1), Some(Duration::from_secs(600)));
+        assert_eq!(alice.delta(2), None);
+        assert_eq!(leaderboard.days(), [1, 2]);
+
+        assert!(parse_leaderboard("<html>log in</html>").is_err());
+    }
+
+    #[test]
+    fn shows_days() {
+        let leaderboard = parse_leaderboard(JSON).unwrap();
+        let timings = Timings::parse("[day-01]\npart1 = 0.00025\n").unwrap();
+        let text = to_text(&leaderboard, None, Some(&timings));
+        let lines = text.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "2023 private leaderboard, 3 members");
+        assert_eq!(lines[2], "Rank  Member               Score  Stars");
+        assert_eq!(lines[3], "1     (anonymous user #2)  12     2");
+        assert_eq!(lines[7], "Day 01");
+        assert_eq!(lines[9], "Alice                00:05:00  00:15:00     00:10:00");
+        assert_eq!(lines[10], "(anonymous user #2)  00:01:00  1d 00:00:00  23:59:00");
+        assert_eq!(lines[11], "Our solutions: part 1 250.00µs");
+        assert_eq!(lines[13], "Day 02");
+        assert_eq!(lines.last(), Some(&"Alice   01:53:20"));
+
+        let text = to_text(&leaderboard, Some(3), None);
+        assert!(text.ends_with("Day 03\nNo one has solved it yet"));
+    }
+
+    #[test]
+    fn caches_downloads() {
+        let dir = TempDir::new("leaderboard");
+        let cache = dir.join("2023-1.json");
+
+        let (url, server) = serve("200 OK", JSON);
+        let leaderboard = fetch_leaderboard(|| Ok(Client::new(&url, "secret")), Some(&cache), 2023, 1, SystemTime::now()).unwrap();
+        assert_eq!(leaderboard.members.len(), 3);
+        assert!(server.join().unwrap().head.starts_with("GET /2023/leaderboard/private/view/1.json "));
+
+        // The server has gone, so this would fail if it asked again.
+        let cached = fetch_leaderboard(|| panic!("the client to not be needed"), Some(&cache), 2023, 1, SystemTime::now()).unwrap();
+        assert_eq!(cached, leaderboard);
+
+        // Once it is stale, it is downloaded again, but a bad response isn't kept.
+        let later = SystemTime::now() + Duration::from_secs(16 * 60);
+        let (url, server) = serve("200 OK", "<html>log in</html>");
+        assert!(fetch_leaderboard(|| Ok(Client::new(&url, "secret")), Some(&cache), 2023, 1, later).is_err());
+        server.join().unwrap();
+        assert_eq!(parse_leaderboard(&fs::read_to_string(&cache).unwrap()).unwrap(), leaderboard);
+    }
+}
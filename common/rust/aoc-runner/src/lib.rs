@@ -0,0 +1,34 @@
+mod answers;
+mod cli;
+mod client;
+mod day;
+mod error;
+mod example;
+mod input;
+mod leaderboard;
+mod progress;
+mod puzzle;
+mod report;
+mod run;
+mod solution;
+mod submit;
+mod timing;
+
+#[cfg(test)]
+mod testing;
+
+pub use answers::{check_answer, record_answer, record_wrong, Answers, Mistake, ANSWERS_FILE};
+pub use cli::{main, parse_day};
+pub use client::{fetch_input, session, Client, Fetched, BASE_URL_VAR, SESSION_VAR};
+pub use day::{Day, Execution, Part, Solved};
+pub use error::Error;
+pub use example::{check_examples, examples, parse_example, Example};
+pub use input::{input_dir, input_path, named_input_path, named_inputs, Input, NamedInput, Request, Source, INPUT_DIR_VAR, INPUT_VAR};
+pub use leaderboard::{fetch_leaderboard, leaderboard_cache_path, parse_leaderboard, Leaderboard, Member};
+pub use progress::{repository_root, timings_path, to_grid, to_markdown, update_readme, year_workspaces, DayProgress, Progress, Timings};
+pub use puzzle::{archive, parse_puzzle, puzzle_path, Archived, Puzzle};
+pub use report::{Format, Record, Status};
+pub use run::{catch, run_day};
+pub use solution::{normalise, Solution};
+pub use submit::{parse_response, submit, Outcome, Submission, Submitted, Throttle, Verdict};
+pub use timing::{Measurement, PartMeasurement, Samples};
@@ -0,0 +1,338 @@
+use std::{collections::BTreeMap, fs, io, path::{Path, PathBuf}, time::Duration};
+use serde::Deserialize;
+use crate::{
+    answers::{self, Answers},
+    day::Part,
+    report::{self, Record, Status},
+    timing::format_duration,
+    Error,
+};
+
+const HEADER: &str = "\
+# How long each part with a verified answer took, in seconds, the last time it
+# was run on the day's own input by a release build of the `aoc` runner.
+";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayTimings {
+    part1: Option<f64>,
+    part2: Option<f64>,
+}
+
+// The last timings of each verified part, as kept in a year's `timings.toml`
+// within the input directory. They change with every run, so they are kept out
+// of the repository. Only the median of each run is kept.
+#[derive(Debug, Default, PartialEq)]
+pub struct Timings(BTreeMap<(u8, Part), f64>);
+
+impl Timings {
+    pub fn parse(text: &str) -> Result<Timings, String> {
+        let days = toml::from_str::<BTreeMap<String, DayTimings>>(text).map_err(|error| error.message().to_owned())?;
+
+        let mut timings = BTreeMap::new();
+        for (name, day) in days {
+            let number = crate::day::parse_name(&name).ok_or_else(|| format!("`{name}` is not a day, expected `day-XX`"))?;
+            for (part, seconds) in [(Part::One, day.part1), (Part::Two, day.part2)] {
+                if let Some(seconds) = seconds {
+                    timings.insert((number, part), seconds);
+                }
+            }
+        }
+
+        Ok(Timings(timings))
+    }
+
+    // Reads a year's `timings.toml`. A year without one simply hasn't been
+    // timed yet.
+    pub fn load(path: &Path) -> Result<Timings, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Timings::default()),
+            Err(source) => return Err(Error::ReadTimings { path: path.to_owned(), source }),
+        };
+
+        Timings::parse(&text).map_err(|message| Error::InvalidTimings { path: path.to_owned(), message })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<Duration> {
+        self.0.get(&(day, part)).map(|&seconds| Duration::from_secs_f64(seconds))
+    }
+
+    // Keep the time of every part that matched the verified answer for the
+    // day's own input, returning whether anything was kept.
+    pub fn update(&mut self, records: &[Record]) -> bool {
+        let mut changed = false;
+        for record in records {
+            if !record.verified || record.input_name.is_some() || record.status != Status::Pass {
+                continue;
+            }
+            if let Some(seconds) = record.duration {
+                self.0.insert((record.day, record.part()), seconds);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut document = toml_edit::DocumentMut::new();
+        for (&(day, part), &seconds) in &self.0 {
+            let table = document.entry(&answers::day_key(day)).or_insert(toml_edit::table());
+            table[&format!("part{}", part.number())] = toml_edit::value(seconds);
+        }
+        format!("{HEADER}\n{document}")
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let saved = match path.parent() {
+            Some(dir) => fs::create_dir_all(dir).and_then(|()| fs::write(path, self.to_toml())),
+            None => fs::write(path, self.to_toml()),
+        };
+        saved.map_err(|source| Error::WriteTimings { path: path.to_owned(), source })
+    }
+}
+
+// A year's timings within the input directory, such as `2023/timings.toml`.
+pub fn timings_path(input_dir: &Path, year: u16) -> PathBuf {
+    input_dir.join(year.to_string()).join("timings.toml")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: u8,
+    // Whether each part has a verified answer, which is what earns its star.
+    pub solved: [bool; 2],
+    pub timings: [Option<Duration>; 2],
+}
+
+impl DayProgress {
+    pub fn stars(&self) -> usize {
+        self.solved.iter().filter(|&&solved| solved).count()
+    }
+}
+
+// How far through a year's puzzles we are, with every day of the calendar.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub year: u16,
+    pub days: Vec<DayProgress>,
+}
+
+impl Progress {
+    pub fn new(year: u16, answers: &Answers, timings: &Timings) -> Progress {
+        let days = (1..=25)
+            .map(|day| DayProgress {
+                day,
+                solved: Part::ALL.map(|part| answers.get(day, None, part).is_some()),
+                timings: Part::ALL.map(|part| timings.get(day, part)),
+            })
+            .collect();
+
+        Progress { year, days }
+    }
+
+    // Without an input directory there are no timings, only stars.
+    pub fn load(year: u16, workspace: &Path, input_dir: Option<&Path>) -> Result<Progress, Error> {
+        let timings = match input_dir {
+            Some(input_dir) => Timings::load(&timings_path(input_dir, year))?,
+            None => Timings::default(),
+        };
+        Ok(Progress::new(year, &Answers::load(workspace)?, &timings))
+    }
+
+    pub fn stars(&self) -> usize {
+        self.days.iter().map(DayProgress::stars).sum()
+    }
+}
+
+// The directory named after the workspace's year, such as `2023` for
+// `2023/rust`.
+fn year_dir(workspace: &Path) -> Option<&Path> {
+    workspace
+        .ancestors()
+        .find(|dir| dir.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.parse::<u16>().is_ok()))
+}
+
+// The repository holding every year, which is where its `README.md` lives.
+pub fn repository_root(workspace: &Path) -> Option<&Path> {
+    year_dir(workspace)?.parent()
+}
+
+// Every year's workspace in the repository for the same language as this one,
+// such as `2023/rust` and `2024/rust`, in order of their years.
+pub fn year_workspaces(workspace: &Path) -> Vec<(u16, PathBuf)> {
+    let Some(year_dir) = year_dir(workspace) else {
+        return Vec::new();
+    };
+    let language = workspace.strip_prefix(year_dir).unwrap_or(Path::new(""));
+    let Some(entries) = year_dir.parent().and_then(|root| fs::read_dir(root).ok()) else {
+        return Vec::new();
+    };
+
+    let mut years = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let year = entry.file_name().to_str()?.parse().ok()?;
+            let workspace = entry.path().join(language);
+            workspace.join("Cargo.toml").is_file().then_some((year, workspace))
+        })
+        .collect::<Vec<_>>();
+    years.sort();
+    years
+}
+
+fn stars(day: &DayProgress, star: &str, missing: &str) -> String {
+    day.solved.iter().map(|&solved| if solved { star } else { missing }).collect()
+}
+
+fn timing(timing: Option<Duration>) -> String {
+    timing.map(format_duration).unwrap_or_default()
+}
+
+// The year's calendar, five days to a row with a `*` for each solved part,
+// followed by how long each solved day last took.
+pub fn to_grid(progress: &Progress) -> String {
+    let mut lines = vec![format!("{}: {} of {} stars", progress.year, progress.stars(), progress.days.len() * 2), String::new()];
+    for row in progress.days.chunks(5) {
+        let cells = row.iter().map(|day| format!("{:>2} {}", day.day, stars(day, "*", "."))).collect::<Vec<_>>();
+        lines.push(cells.join("   "));
+    }
+
+    let solved = progress.days.iter().filter(|day| day.stars() > 0).collect::<Vec<_>>();
+    if solved.is_empty() {
+        return lines.join("\n");
+    }
+
+    let mut rows = vec![["Day", "Stars", "Part 1", "Part 2"].map(str::to_owned).to_vec()];
+    for day in solved {
+        rows.push(vec![format!("{:02}", day.day), stars(day, "*", "."), timing(day.timings[0]), timing(day.timings[1])]);
+    }
+    lines.push(String::new());
+    lines.extend(report::align(&rows));
+
+    lines.join("\n")
+}
+
+// A table for each year, of the days with at least one star, for the README.
+// Timings differ between machines and runs, so they are left out of it.
+pub fn to_markdown(years: &[Progress]) -> String {
+    let mut lines = Vec::new();
+    for progress in years {
+        lines.push(format!("### {}", progress.year));
+        lines.push(String::new());
+        lines.push(format!("{} of {} stars", progress.stars(), progress.days.len() * 2));
+        lines.push(String::new());
+        lines.push("| Day | Stars |".to_owned());
+        lines.push("| --- | --- |".to_owned());
+        for day in progress.days.iter().filter(|day| day.stars() > 0) {
+            lines.push(format!("| {:02} | {} |", day.day, stars(day, "⭐", "")));
+        }
+        lines.push(String::new());
+    }
+
+    lines.join("\n")
+}
+
+const README_START: &str = "<!-- progress -->";
+const README_END: &str = "<!-- /progress -->";
+
+// Replace what is between the progress markers in the README, or add a new
+// section for it at the end when there are no markers yet.
+pub fn update_readme(readme: &str, progress: &str) -> String {
+    let section = format!("{README_START}\n{progress}{README_END}");
+
+    if let Some((before, rest)) = readme.split_once(README_START) {
+        let after = rest.split_once(README_END).map_or("", |(_, after)| after);
+        return format!("{before}{section}{after}");
+    }
+
+    format!("{}\n\n## Progress\n{section}\n", readme.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+    use super::{timings_path, to_grid, to_markdown, update_readme, year_workspaces, Progress, Timings};
+    use crate::{answers::Answers, day::Part, report::{Record, Status}, testing::TempDir};
+
+    fn progress() -> Progress {
+        let answers = Answers::parse("[day-01]\npart1 = 1\npart2 = 2\n\n[day-02]\npart1 = 3\n\n[day-03]\npart1_wrong = [4]\n").unwrap();
+        let timings = Timings::parse("[day-01]\npart1 = 0.00025\npart2 = 1.5\n").unwrap();
+        Progress::new(2024, &answers, &timings)
+    }
+
+    #[test]
+    fn counts_stars() {
+        let progress = progress();
+        assert_eq!(progress.days.len(), 25);
+        assert_eq!(progress.stars(), 3);
+        assert_eq!(progress.days[0].timings, [Some(Duration::from_micros(250)), Some(Duration::from_millis(1500))]);
+        assert_eq!(progress.days[1].solved, [true, false]);
+        assert_eq!(progress.days[2].stars(), 0);
+    }
+
+    #[test]
+    fn keeps_verified_timings() {
+        let mut record = Record::from_error(2024, 2, Part::One, Status::Pass, "");
+        record.duration = Some(0.5);
+
+        let mut timings = Timings::default();
+        assert!(!timings.update(&[record]));
+
+        let mut record = Record::from_error(2024, 2, Part::One, Status::Pass, "");
+        record.duration = Some(0.5);
+        record.verified = true;
+        assert!(timings.update(&[record]));
+        assert_eq!(timings.get(2, Part::One), Some(Duration::from_millis(500)));
+
+        let text = timings.to_toml();
+        assert!(text.starts_with("# How long"));
+        assert!(text.ends_with("[day-02]\npart1 = 0.5\n"));
+        assert_eq!(Timings::parse(&text).unwrap(), timings);
+
+        let dir = TempDir::new("timings");
+        let path = timings_path(&dir, 2024);
+        assert_eq!(path, dir.join("2024/timings.toml"));
+        assert_eq!(Timings::load(&path).unwrap(), Timings::default());
+        timings.save(&path).unwrap();
+        assert_eq!(Timings::load(&path).unwrap(), timings);
+    }
+
+    #[test]
+    fn draws_grid() {
+        let grid = to_grid(&progress());
+        let lines = grid.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "2024: 3 of 50 stars");
+        assert_eq!(lines[2], " 1 **    2 *.    3 ..    4 ..    5 ..");
+        assert_eq!(lines[6], "21 ..   22 ..   23 ..   24 ..   25 ..");
+        assert_eq!(lines[8], "Day  Stars  Part 1    Part 2");
+        assert_eq!(lines[9], "01   **     250.00µs  1.50s");
+        assert_eq!(lines[10], "02   *.");
+    }
+
+    #[test]
+    fn writes_readme() {
+        let table = to_markdown(&[progress()]);
+        assert!(table.starts_with("### 2024\n\n3 of 50 stars\n\n| Day | Stars |\n"));
+        assert!(table.contains("| 01 | ⭐⭐ |\n| 02 | ⭐ |\n"));
+
+        let readme = update_readme("# Advent of Code\n", "new\n");
+        assert_eq!(readme, "# Advent of Code\n\n## Progress\n<!-- progress -->\nnew\n<!-- /progress -->\n");
+        let readme = update_readme(&readme.replace("new", "old"), "new\n");
+        assert_eq!(readme, "# Advent of Code\n\n## Progress\n<!-- progress -->\nnew\n<!-- /progress -->\n");
+    }
+
+    #[test]
+    fn finds_years() {
+        let dir = TempDir::new("progress");
+        for path in ["2023/rust", "2024/rust", "2024/python", "2025", "common/rust"] {
+            fs::create_dir_all(dir.join(path)).unwrap();
+            fs::write(dir.join(path).join("Cargo.toml"), "").unwrap();
+        }
+
+        let years = year_workspaces(&dir.join("2024/rust"));
+        assert_eq!(years, [(2023, dir.join("2023/rust")), (2024, dir.join("2024/rust"))]);
+        assert!(year_workspaces(&dir.join("common/rust")).is_empty());
+    }
+}
@@ -0,0 +1,363 @@
+use std::{fs, path::{Path, PathBuf}};
+use crate::{example::parse_example, Error};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: &'a str, attributes: &'a str },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+// Split a page into tags and the text between them. Puzzle pages are simple
+// enough that there's no need for a real parser.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+            continue;
+        }
+
+        match rest.find('<') {
+            Some(0) => {
+                // A tag that never ends is only text, such as a page that was
+                // cut short.
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let tag = rest[1..end].trim_end_matches('/');
+                rest = &rest[end + 1..];
+
+                match tag.strip_prefix('/') {
+                    Some(name) => tokens.push(Token::Close(name.trim())),
+                    None => {
+                        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                        tokens.push(Token::Open { name, attributes });
+                    },
+                }
+            },
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            },
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            },
+        }
+    }
+
+    tokens
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';').filter(|&end| end <= 8) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let entity = &rest[1..end];
+        let character = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+
+        match character {
+            Some(character) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = attributes.split_once(&format!("{name}=\""))?;
+    rest.split_once('"').map(|(value, _)| value)
+}
+
+// A puzzle's description, and each of its examples. An example is a
+// `<pre><code>` block straight after a paragraph that mentions one, such as
+// `For example:`, as other blocks are often only part of the explanation.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+// Convert the description of a saved puzzle page to Markdown. Only the parts of
+// the page that describe the puzzle are kept, which is part two as well once it
+// has been unlocked.
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let mut puzzle = Puzzle::default();
+    let mut line = String::new();
+    let mut example: Option<String> = None;
+    // The paragraph just before the block being read, if that's what it
+    // follows, and whether the block has started with its code.
+    let mut paragraph = String::new();
+    let mut is_example = false;
+    let mut in_pre = false;
+    let mut in_article = false;
+    let mut headings = 0;
+    // Where the current inline code started, and whether it was emphasised.
+    let mut code: Option<(usize, bool)> = None;
+    let mut links = Vec::new();
+
+    for token in tokenize(html) {
+        if !in_article {
+            if let Token::Open { name: "article", attributes } = token {
+                in_article = attribute(attributes, "class").is_some_and(|class| class.split(' ').any(|class| class == "day-desc"));
+            }
+            continue;
+        }
+
+        // Examples are kept exactly as they are, without any formatting.
+        if let Some(text) = example.as_mut() {
+            if !in_pre {
+                is_example &= matches!(token, Token::Open { name: "code", .. });
+                in_pre = true;
+            }
+
+            match token {
+                Token::Text(raw) => text.push_str(&decode(raw)),
+                Token::Close("pre") => {
+                    let mut text = example.take().unwrap_or_default();
+                    if !text.ends_with('\n') {
+                        text.push('\n');
+                    }
+                    puzzle.markdown.push_str(&format!("```\n{text}```\n\n"));
+                    if is_example {
+                        puzzle.examples.push(text);
+                    }
+                    paragraph.clear();
+                },
+                _ => {},
+            }
+            continue;
+        }
+
+        match token {
+            Token::Open { name: "pre", .. } => {
+                is_example = paragraph.to_lowercase().contains("example");
+                in_pre = false;
+                example = Some(String::new());
+            },
+            Token::Open { name: "li", .. } => line.push_str("- "),
+            Token::Open { name: "code", .. } => {
+                code = Some((line.len(), false));
+                line.push('`');
+            },
+            Token::Close("code") => {
+                line.push('`');
+                if let Some((start, true)) = code.take() {
+                    line.insert_str(start, "**");
+                    line.push_str("**");
+                }
+            },
+            Token::Open { name: "em", .. } | Token::Close("em") => match code.as_mut() {
+                Some((_, emphasised)) => *emphasised = true,
+                None => line.push_str("**"),
+            },
+            Token::Open { name: "a", attributes } => {
+                links.push(attribute(attributes, "href").unwrap_or_default().to_owned());
+                line.push('[');
+            },
+            Token::Close("a") => line.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+            Token::Close("h2") => {
+                let title = line.trim().trim_matches('-').trim();
+                let level = if headings == 0 { "#" } else { "##" };
+                puzzle.markdown.push_str(&format!("{level} {title}\n\n"));
+                headings += 1;
+                line.clear();
+                paragraph.clear();
+            },
+            Token::Close("p") => {
+                puzzle.markdown.push_str(&format!("{}\n\n", line.trim()));
+                paragraph = std::mem::take(&mut line);
+            },
+            Token::Close("li") => {
+                puzzle.markdown.push_str(&format!("{}\n", line.trim()));
+                line.clear();
+                paragraph.clear();
+            },
+            Token::Close("ul") => puzzle.markdown.push('\n'),
+            Token::Close("article") => in_article = false,
+            Token::Text(raw) => line.push_str(&decode(raw).replace('\n', " ")),
+            _ => {},
+        }
+    }
+
+    puzzle.markdown = format!("{}\n", puzzle.markdown.trim_end());
+    puzzle
+}
+
+// The puzzle's description is kept with the inputs, as it isn't ours to
+// publish either.
+pub fn puzzle_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("{day:02}.md"))
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Archived {
+    pub markdown: PathBuf,
+    pub written: Vec<PathBuf>,
+    // Examples that were already saved, and so were left as they were.
+    pub kept: Vec<PathBuf>,
+}
+
+// Save the description with the inputs, and each example to the day's
+// `examples` directory as `1.txt`, `2.txt` and so on. Examples that already
+// exist may have been edited, so they are never overwritten, unless they are
+// still blank like the one a new day starts with, which keeps its header.
+pub fn archive(puzzle: &Puzzle, markdown: &Path, day_dir: &Path) -> Result<Archived, Error> {
+    let write = |path: &Path, text: &str| {
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, text));
+        result.map_err(|source| Error::WritePuzzle { path: path.to_owned(), source })
+    };
+
+    write(markdown, &puzzle.markdown)?;
+    let mut archived = Archived { markdown: markdown.to_owned(), ..Archived::default() };
+
+    for (index, example) in puzzle.examples.iter().enumerate() {
+        let path = day_dir.join("examples").join(format!("{}.txt", index + 1));
+        let header = match path.exists().then(|| fs::read_to_string(&path).ok()) {
+            None => String::new(),
+            Some(Some(text)) if parse_example(&text).input.trim().is_empty() => {
+                let input = parse_example(&text).input;
+                text[..text.len() - input.len()].to_owned()
+            },
+            Some(_) => {
+                archived.kept.push(path);
+                continue;
+            },
+        };
+
+        write(&path, &format!("{header}{example}"))?;
+        archived.written.push(path);
+    }
+
+    Ok(archived)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{archive, decode, parse_puzzle, tokenize, Token};
+    use crate::testing::TempDir;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>Day 6 - Advent of Code 2023</title></head><body>
+<header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 6: Wait For It ---</h2><p>The race is won by going <em>farther</em> than the record &amp; the <a href="/2023/about">boat</a> starts at <code>0</code>.</p>
+<p>For example:</p>
+<pre><code>Time:      7  15   30
+Distance:  9  40  &lt;200&gt;
+</code></pre>
+<ul>
+<li>Hold it for <code>1</code> millisecond.</li>
+<li>Multiply them to get <code><em>288</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>2065338</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>There's really only one race:</p>
+<pre><code>Time:      71530
+Distance:  940200</code></pre>
+<p>Here's another example:</p>
+<pre><code>Time: 1
+</code></pre>
+</article>
+<!-- <article class="day-desc">not this</article> -->
+</main></body></html>"#;
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(decode("&lt;a&gt; &amp; &#39;b&#x27; &unknown; a & b"), "<a> & 'b' &unknown; a & b");
+    }
+
+    #[test]
+    fn converts_descriptions() {
+        let puzzle = parse_puzzle(PAGE);
+        assert_eq!(
+            puzzle.markdown,
+            "# Day 6: Wait For It\n\n\
+             The race is won by going **farther** than the record & the [boat](/2023/about) starts at `0`.\n\n\
+             For example:\n\n\
+             ```\nTime:      7  15   30\nDistance:  9  40  <200>\n```\n\n\
+             - Hold it for `1` millisecond.\n\
+             - Multiply them to get **`288`**.\n\n\
+             ## Part Two\n\n\
+             There's really only one race:\n\n\
+             ```\nTime:      71530\nDistance:  940200\n```\n\n\
+             Here's another example:\n\n\
+             ```\nTime: 1\n```\n",
+        );
+        // The block in part two only follows an explanation, not an example.
+        assert_eq!(puzzle.examples, ["Time:      7  15   30\nDistance:  9  40  <200>\n", "Time: 1\n"]);
+
+        // A block without its code isn't an example either.
+        let puzzle = parse_puzzle(r#"<article class="day-desc"><p>For example:</p><pre>1 2</pre></article>"#);
+        assert!(puzzle.examples.is_empty());
+    }
+
+    #[test]
+    fn tokenizes_unfinished_pages() {
+        assert_eq!(tokenize("a <b"), [Token::Text("a "), Token::Text("<b")]);
+        assert_eq!(tokenize("<p>a<"), [Token::Open { name: "p", attributes: "" }, Token::Text("a"), Token::Text("<")]);
+        assert_eq!(parse_puzzle(r#"<article class="day-desc"><p>cut short <"#).markdown, "\n");
+    }
+
+    #[test]
+    fn keeps_existing_examples() {
+        let dir = TempDir::new("puzzle");
+        fs::create_dir_all(dir.join("day-06/examples")).unwrap();
+        fs::write(dir.join("day-06/examples/1.txt"), "edited").unwrap();
+
+        let archived = archive(&parse_puzzle(PAGE), &dir.join("inputs/2023/06.md"), &dir.join("day-06")).unwrap();
+        assert_eq!(archived.kept, [dir.join("day-06/examples/1.txt")]);
+        assert_eq!(archived.written, [dir.join("day-06/examples/2.txt")]);
+        assert_eq!(fs::read_to_string(dir.join("day-06/examples/2.txt")).unwrap(), "Time: 1\n");
+        assert_eq!(fs::read_to_string(dir.join("day-06/examples/1.txt")).unwrap(), "edited");
+        assert!(fs::read_to_string(dir.join("inputs/2023/06.md")).unwrap().starts_with("# Day 6"));
+
+        // The blank example that a new day starts with is filled in.
+        fs::write(dir.join("day-06/examples/1.txt"), "part1:\npart2:\n---\n").unwrap();
+        let archived = archive(&parse_puzzle(PAGE), &dir.join("inputs/2023/06.md"), &dir.join("day-06")).unwrap();
+        assert_eq!(archived.written, [dir.join("day-06/examples/1.txt")]);
+        assert_eq!(
+            fs::read_to_string(dir.join("day-06/examples/1.txt")).unwrap(),
+            "part1:\npart2:\n---\nTime:      7  15   30\nDistance:  9  40  <200>\n",
+        );
+    }
+}
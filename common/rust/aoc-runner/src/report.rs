@@ -0,0 +1,406 @@
+use std::{fmt, time::Duration};
+use clap::ValueEnum;
+use serde::Serialize;
+use crate::{
+    day::Part,
+    timing::{format_duration, Measurement},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    // The answer matches the known answer.
+    Pass,
+    // The answer doesn't match the known answer, or is one that is known to be
+    // wrong.
+    Fail,
+    // There is no known answer to check against, or a different input was used.
+    Unverified,
+    Panic,
+    // The part couldn't be run at all, such as when there is no input.
+    Error,
+}
+
+impl Status {
+    pub fn check(answer: &str, expected: Option<&str>) -> Status {
+        match expected {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Unverified,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail | Status::Panic | Status::Error)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "fail"),
+            Status::Unverified => write!(f, "unverified"),
+            Status::Panic => write!(f, "panic"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+// A single part of a single day, as it is written out in JSON. Durations are
+// in seconds, and `duration` and `parse` are the medians when the puzzle was
+// repeated.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    // Which user's input was solved, when it wasn't the day's own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_name: Option<String>,
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    pub duration: Option<f64>,
+    pub min: Option<f64>,
+    pub p95: Option<f64>,
+    pub runs: usize,
+    pub parse: Option<f64>,
+    pub parse_min: Option<f64>,
+    pub parse_p95: Option<f64>,
+    pub input: Option<f64>,
+    pub status: Status,
+    // Whether the answer was checked against `answers.toml`, rather than an
+    // example's own answers.
+    #[serde(skip)]
+    pub verified: bool,
+    // Why an unverified answer is already known to be wrong.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mistake: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    // `expected` is only given when the day's own input was used.
+    pub fn from_measurement<'a>(
+        year: u16,
+        day: u8,
+        measurement: &Measurement,
+        expected: impl Fn(Part) -> Option<&'a str>,
+    ) -> Vec<Record> {
+        measurement
+            .parts
+            .iter()
+            .map(|part| {
+                let expected = expected(part.part);
+                Record {
+                    year,
+                    day,
+                    part: part.part.number(),
+                    input_name: None,
+                    answer: Some(part.answer.clone()),
+                    expected: expected.map(str::to_owned),
+                    duration: Some(part.samples.median().as_secs_f64()),
+                    min: Some(part.samples.min().as_secs_f64()),
+                    p95: Some(part.samples.p95().as_secs_f64()),
+                    runs: part.samples.len(),
+                    parse: Some(measurement.parse.median().as_secs_f64()),
+                    parse_min: Some(measurement.parse.min().as_secs_f64()),
+                    parse_p95: Some(measurement.parse.p95().as_secs_f64()),
+                    input: Some(measurement.load.as_secs_f64()),
+                    status: Status::check(&part.answer, expected),
+                    verified: false,
+                    mistake: None,
+                    error: None,
+                }
+            })
+            .collect()
+    }
+
+    pub fn from_error(year: u16, day: u8, part: Part, status: Status, error: &str) -> Record {
+        Record {
+            year,
+            day,
+            part: part.number(),
+            input_name: None,
+            answer: None,
+            expected: None,
+            duration: None,
+            min: None,
+            p95: None,
+            runs: 0,
+            parse: None,
+            parse_min: None,
+            parse_p95: None,
+            input: None,
+            status,
+            verified: false,
+            mistake: None,
+            error: Some(error.to_owned()),
+        }
+    }
+
+    pub(crate) fn part(&self) -> Part {
+        Part::new(self.part).expect("records to hold a valid part")
+    }
+
+    // The part, along with whose input it was when it wasn't the day's own.
+    fn label(&self) -> String {
+        match &self.input_name {
+            Some(name) => format!("Part {} ({name})", self.part()),
+            None => format!("Part {}", self.part()),
+        }
+    }
+
+    // What to show for the answer in a report, which explains what went wrong
+    // when there isn't one.
+    fn describe(&self) -> String {
+        if let (Some(answer), Some(mistake)) = (&self.answer, &self.mistake) {
+            return format!("{answer} ({mistake})");
+        }
+
+        match (&self.answer, &self.error) {
+            (Some(answer), _) if self.status == Status::Fail => {
+                format!("{answer} (expected {})", self.expected.as_deref().unwrap_or_default())
+            },
+            (Some(answer), _) => answer.clone(),
+            (None, Some(error)) if self.status == Status::Panic => format!("panicked: {error}"),
+            (None, Some(error)) => error.clone(),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn timing(&self) -> String {
+        statistics(self.min, self.duration, self.p95, self.runs)
+    }
+
+    fn parse_timing(&self) -> String {
+        statistics(self.parse_min, self.parse, self.parse_p95, self.runs)
+    }
+}
+
+// A single run's time, or the min, median and p95 of every run.
+fn statistics(min: Option<f64>, median: Option<f64>, p95: Option<f64>, runs: usize) -> String {
+    let Some(median) = median else {
+        return String::new();
+    };
+
+    if runs <= 1 {
+        return seconds(median);
+    }
+
+    format!(
+        "min {}, median {}, p95 {} ({runs} runs)",
+        seconds(min.unwrap_or_default()),
+        seconds(median),
+        seconds(p95.unwrap_or_default()),
+    )
+}
+
+fn seconds(seconds: f64) -> String {
+    format_duration(Duration::from_secs_f64(seconds))
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records to always serialise")
+}
+
+// The report for a single day, with its answers and then how long they took.
+pub fn to_text(records: &[Record]) -> String {
+    let mut lines = vec!["Answers".to_owned()];
+    for record in records {
+        lines.push(format!("{}: {}", record.label(), record.describe()));
+    }
+
+    let Some(first) = records.iter().find(|record| record.duration.is_some()) else {
+        return lines.join("\n");
+    };
+
+    lines.push(String::new());
+    lines.push("Timings".to_owned());
+    lines.push(format!("Input:    {}", seconds(first.input.unwrap_or_default())));
+    lines.push(format!("Parse:    {}", first.parse_timing()));
+    for record in records.iter().filter(|record| record.duration.is_some()) {
+        lines.push(format!("{}: {}", record.label(), record.timing()));
+    }
+
+    lines.join("\n")
+}
+
+// Line up the cells of each row into columns, each as wide as its widest cell.
+pub(crate) fn align(rows: &[Vec<String>]) -> Vec<String> {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row.get(column).map_or(0, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_owned()
+        })
+        .collect()
+}
+
+// The report for every day in a year, as a table followed by a summary. Whose
+// input each row is for is only shown when there are other users' inputs.
+pub fn to_table(records: &[Record]) -> String {
+    let named = records.iter().any(|record| record.input_name.is_some());
+    let columns = |row: [String; 7]| {
+        row.into_iter()
+            .enumerate()
+            .filter(|&(column, _)| named || column != 2)
+            .map(|(_, cell)| cell)
+            .collect::<Vec<_>>()
+    };
+
+    let header = columns(["Day", "Part", "Input", "Answer", "Parse", "Solve", "Status"].map(str::to_owned));
+    let rows = records
+        .iter()
+        .map(|record| {
+            columns([
+                format!("{:02}", record.day),
+                record.part.to_string(),
+                record.input_name.clone().unwrap_or_default(),
+                record.describe(),
+                record.parse_timing(),
+                record.timing(),
+                record.status.to_string(),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = align(&std::iter::once(header).chain(rows).collect::<Vec<_>>());
+
+    let count = |status: Status| records.iter().filter(|record| record.status == status).count();
+    lines.push(String::new());
+    lines.push(format!(
+        "{} passed, {} failed, {} unverified, {} panicked, {} errored",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Unverified),
+        count(Status::Panic),
+        count(Status::Error),
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{to_json, to_table, to_text, Record, Status};
+    use crate::{
+        day::Part,
+        timing::{Measurement, PartMeasurement, Samples},
+    };
+
+    fn measurement() -> Measurement {
+        Measurement {
+            load: Duration::from_millis(1),
+            parse: Samples::from(vec![Duration::from_millis(3), Duration::from_millis(1)]),
+            parts: vec![PartMeasurement {
+                part: Part::Two,
+                answer: "46".to_owned(),
+                samples: Samples::from(vec![Duration::from_millis(250), Duration::from_millis(750)]),
+            }],
+        }
+    }
+
+    #[test]
+    fn checks_answers() {
+        assert_eq!(Status::check("46", Some("46")), Status::Pass);
+        assert_eq!(Status::check("46", Some("45")), Status::Fail);
+        assert_eq!(Status::check("46", None), Status::Unverified);
+    }
+
+    #[test]
+    fn json_records() {
+        let records = Record::from_measurement(2023, 5, &measurement(), |_| Some("46"));
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records)).unwrap();
+
+        assert_eq!(json[0]["year"], 2023);
+        assert_eq!(json[0]["day"], 5);
+        assert_eq!(json[0]["part"], 2);
+        assert_eq!(json[0]["answer"], "46");
+        assert_eq!(json[0]["duration"], 0.5);
+        assert_eq!(json[0]["runs"], 2);
+        assert_eq!(json[0]["parse_min"], 0.001);
+        assert_eq!(json[0]["parse"], 0.002);
+        assert_eq!(json[0]["parse_p95"], 0.003);
+        assert_eq!(json[0]["status"], "pass");
+        assert!(json[0].get("error").is_none());
+    }
+
+    #[test]
+    fn json_errors() {
+        let records = vec![Record::from_error(2024, 3, Part::One, Status::Error, "no input")];
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records)).unwrap();
+
+        assert_eq!(json[0]["status"], "error");
+        assert_eq!(json[0]["answer"], serde_json::Value::Null);
+        assert_eq!(json[0]["error"], "no input");
+    }
+
+    #[test]
+    fn text_report() {
+        let text = to_text(&Record::from_measurement(2023, 5, &measurement(), |_| Some("45")));
+        assert!(text.starts_with("Answers\nPart two: 46 (expected 45)\n\nTimings\n"));
+        assert!(text.contains("\nParse:    min 1.00ms, median 2.00ms, p95 3.00ms (2 runs)\n"));
+        assert!(text.ends_with("Part two: min 250.00ms, median 500.00ms, p95 750.00ms (2 runs)"));
+    }
+
+    #[test]
+    fn table_report() {
+        let mut records = Record::from_measurement(2023, 5, &measurement(), |_| None);
+        records.push(Record::from_error(2023, 6, Part::One, Status::Panic, "oh no"));
+
+        let table = to_table(&records);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0].split_whitespace().collect::<Vec<_>>(), ["Day", "Part", "Answer", "Parse", "Solve", "Status"]);
+        assert!(lines[1].starts_with("05   2     46 "));
+        assert!(lines[1].contains("  min 1.00ms, median 2.00ms, p95 3.00ms (2 runs)  min 250.00ms"));
+        assert!(lines[1].ends_with("(2 runs)  unverified"));
+        assert!(lines[2].starts_with("06   1     panicked: oh no "));
+        assert!(lines[2].ends_with(" panic"));
+        assert_eq!(lines[0].find("Status"), lines[2].rfind("panic"));
+        assert_eq!(lines[4], "0 passed, 0 failed, 1 unverified, 1 panicked, 0 errored");
+    }
+
+    #[test]
+    fn named_inputs() {
+        let mut records = Record::from_measurement(2023, 5, &measurement(), |_| None);
+        records.extend(Record::from_measurement(2023, 5, &measurement(), |_| None));
+        records[1].input_name = Some("alice".to_owned());
+
+        let text = to_text(&records);
+        assert!(text.starts_with("Answers\nPart two: 46\nPart two (alice): 46\n"));
+
+        let table = to_table(&records);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0].split_whitespace().collect::<Vec<_>>(), ["Day", "Part", "Input", "Answer", "Parse", "Solve", "Status"]);
+        assert!(lines[2].starts_with("05   2     alice  46 "));
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records)).unwrap();
+        assert!(json[0].get("input_name").is_none());
+        assert_eq!(json[1]["input_name"], "alice");
+    }
+}
@@ -0,0 +1,256 @@
+use std::{any::Any, cell::Cell, fs, panic::{self, AssertUnwindSafe}, path::Path, sync::Once, time::Instant};
+use crate::{
+    answers::Answers,
+    day::{self, Day, Part},
+    input::{self, Input, Request, Source},
+    report::{Record, Status},
+    timing::Measurement,
+    Error,
+};
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return (*message).to_owned();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    "unknown panic".to_owned()
+}
+
+thread_local! {
+    // Whether this thread is inside `catch`, which reports its panics itself.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+// Run `f`, turning a panic into its message. The hook is only ever set once,
+// wrapping the one before it, and stays quiet for panics on a thread that is
+// catching them, so the message is only reported once, by us. Every other
+// panic, on this thread or any other, is reported as usual.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+// Run the given parts of a day, returning a record for each of them. When the
+// day's own input is used, every other user's input is run after it.
+//
+// Both parts are first run together, so each input is only parsed once. If that
+// panics each part is run on its own, so one broken part doesn't hide the other.
+pub fn run_day(year: u16, day: &Day, answers: &Answers, request: &Request, parts: &[Part], repeat: usize) -> Vec<Record> {
+    let errors = |error: Error, name: Option<&str>| {
+        parts
+            .iter()
+            .map(|&part| Record::from_error(year, day.day, part, Status::Error, &error.to_string()))
+            .map(|record| Record { input_name: name.map(str::to_owned), ..record })
+            .collect::<Vec<_>>()
+    };
+
+    let solve = |input: &Input, load, name: Option<&str>, verify: bool| {
+        // Known answers are only for the day's own input, or another user's,
+        // though examples can have their own.
+        let expected = |part| if verify { answers.get(day.day, name, part) } else { input.answer(part) };
+
+        let measure = |parts: &[Part]| catch(|| Measurement::new(day, load, &input.text, parts, repeat));
+        let results = match measure(parts) {
+            Err(_) if parts.len() > 1 => parts.iter().map(|&part| (vec![part], measure(&[part]))).collect(),
+            result => vec![(parts.to_vec(), result)],
+        };
+
+        // An answer that hasn't been verified can still be one that was already
+        // sent and found to be wrong.
+        let check = |mut record: Record| {
+            let mistake = record.answer.as_deref().and_then(|answer| answers.mistake(day.day, name, record.part(), answer));
+            if let Some(mistake) = mistake.filter(|_| verify && record.status == Status::Unverified) {
+                record.status = Status::Fail;
+                record.mistake = Some(mistake.to_string());
+            }
+            Record { input_name: name.map(str::to_owned), verified: verify, ..record }
+        };
+
+        results
+            .into_iter()
+            .flat_map(|(parts, result)| match result {
+                Ok(measurement) => Record::from_measurement(year, day.day, &measurement, expected),
+                Err(message) => parts
+                    .into_iter()
+                    .map(|part| Record::from_error(year, day.day, part, Status::Panic, &message))
+                    .collect(),
+            })
+            .map(check)
+            .collect::<Vec<_>>()
+    };
+
+    let start = Instant::now();
+    let input = match request.resolve() {
+        Ok(input) => input,
+        Err(error) => return errors(error, None),
+    };
+
+    let verify = input.source == Source::File(request.default.to_owned());
+    let mut records = solve(&input, start.elapsed(), None, verify);
+    if !verify {
+        return records;
+    }
+
+    for named in request.named {
+        let start = Instant::now();
+        records.extend(match input::read_file(named.path.clone()) {
+            Ok(input) => solve(&input, start.elapsed(), Some(&named.name), true),
+            Err(error) => errors(error, Some(&named.name)),
+        });
+    }
+
+    records
+}
+
+// Every `day-XX` crate in the workspace, so that days which were never
+// registered with the runner still show up.
+pub fn workspace_days(workspace: &Path) -> Vec<u8> {
+    let Ok(entries) = fs::read_dir(workspace) else {
+        return Vec::new();
+    };
+
+    let mut days = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .filter_map(|entry| entry.file_name().to_str().and_then(day::parse_name))
+        .collect::<Vec<_>>();
+    days.sort();
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{catch, run_day, workspace_days};
+    use crate::{answers::Answers, day::{Day, Part}, input::{self, Request}, report::Status, testing::TempDir, Solution};
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Input = String;
+        type Answer = usize;
+
+        fn parse(input: &str) -> String {
+            input.trim().to_owned()
+        }
+
+        fn part1(input: &String) -> usize {
+            input.len()
+        }
+
+        fn part2(_: &String) -> usize {
+            panic!("not solved yet")
+        }
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch(|| 1), Ok(1));
+        assert_eq!(catch(|| -> u8 { panic!("boom") }), Err("boom".to_owned()));
+        assert_eq!(catch(|| -> u8 { panic!("{}", 42) }), Err("42".to_owned()));
+
+        // Only the thread that is catching is kept quiet, and only while it is.
+        assert_eq!(catch(|| catch(|| -> u8 { panic!("inner") })), Ok(Err("inner".to_owned())));
+        assert!(!super::CATCHING.get());
+        let threads = (0..8)
+            .map(|n| std::thread::spawn(move || catch(|| if n % 2 == 0 { panic!("{n}") } else { n })))
+            .collect::<Vec<_>>();
+        for (n, thread) in threads.into_iter().enumerate() {
+            let expected = if n % 2 == 0 { Err(n.to_string()) } else { Ok(n) };
+            assert_eq!(thread.join().unwrap(), expected);
+        }
+        assert!(!super::CATCHING.get());
+    }
+
+    #[test]
+    fn panicking_part_is_isolated() {
+        let dir = TempDir::new("run");
+        fs::create_dir_all(dir.join("day-03")).unwrap();
+        fs::write(dir.join("day-03/Cargo.toml"), "").unwrap();
+        fs::write(dir.join("03.txt"), "abc").unwrap();
+        fs::create_dir_all(dir.join("day-07")).unwrap();
+
+        let answers = Answers::parse("[day-03]\npart1 = 3\n").unwrap();
+        let (day_dir, default) = (dir.join("day-03"), dir.join("03.txt"));
+        let records = run_day(2023, &Day::new::<Fragile>(3), &answers, &Request::new(&day_dir, &default), &Part::ALL, 1);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].answer.as_deref(), Some("3"));
+        assert_eq!(records[0].status, Status::Pass);
+        assert_eq!(records[1].status, Status::Panic);
+        assert_eq!(records[1].error.as_deref(), Some("not solved yet"));
+
+        // Only crates count, and the directory without a manifest is skipped.
+        assert_eq!(workspace_days(&dir), vec![3]);
+    }
+
+    #[test]
+    fn known_mistakes_fail() {
+        let dir = TempDir::new("run-mistake");
+        fs::write(dir.join("03.txt"), "abc").unwrap();
+
+        let answers = Answers::parse("[day-03]\npart1_too_high = 3\n").unwrap();
+        let default = dir.join("03.txt");
+        let records = run_day(2023, &Day::new::<Fragile>(3), &answers, &Request::new(&dir, &default), &[Part::One], 1);
+        assert_eq!(records[0].status, Status::Fail);
+        assert_eq!(records[0].mistake.as_deref(), Some("not below 3, which was too high"));
+    }
+
+    #[test]
+    fn other_inputs_are_unverified() {
+        let dir = TempDir::new("run-other");
+        fs::write(dir.join("other.txt"), "abc").unwrap();
+
+        let path = dir.join("other.txt");
+        let default = dir.join("03.txt");
+        let request = Request { path: Some(&path), ..Request::new(&dir, &default) };
+        let answers = Answers::parse("[day-03]\npart1 = 3\n").unwrap();
+        let records = run_day(2023, &Day::new::<Fragile>(3), &answers, &request, &[Part::One], 1);
+        assert_eq!(records[0].status, Status::Unverified);
+    }
+
+    #[test]
+    fn named_inputs_are_verified() {
+        let dir = TempDir::new("run-named");
+        fs::create_dir_all(dir.join("2023/03")).unwrap();
+        fs::write(dir.join("2023/03.txt"), "abc").unwrap();
+        fs::write(dir.join("2023/03/alice.txt"), "abcd").unwrap();
+        fs::write(dir.join("2023/03/bob.txt"), "ab").unwrap();
+        fs::write(dir.join("2023/03/notes.md"), "not an input").unwrap();
+
+        let named = input::named_inputs(&dir, 2023, 3);
+        assert_eq!(named.iter().map(|named| named.name.as_str()).collect::<Vec<_>>(), ["alice", "bob"]);
+
+        let answers = Answers::parse("[day-03]\npart1 = 3\n\n[day-03.inputs.alice]\npart1 = 4\n\n[day-03.inputs.bob]\npart1 = 3\n").unwrap();
+        let default = input::input_path(&dir, 2023, 3);
+        let request = Request { named: &named, ..Request::new(&dir, &default) };
+        let records = run_day(2023, &Day::new::<Fragile>(3), &answers, &request, &[Part::One], 1);
+        let results = records
+            .iter()
+            .map(|record| (record.input_name.as_deref(), record.status))
+            .collect::<Vec<_>>();
+        assert_eq!(results, [(None, Status::Pass), (Some("alice"), Status::Pass), (Some("bob"), Status::Fail)]);
+
+        // Only the day's own input has other users' inputs run alongside it.
+        let path = dir.join("2023/03/alice.txt");
+        let request = Request { path: Some(&path), ..request };
+        let records = run_day(2023, &Day::new::<Fragile>(3), &answers, &request, &[Part::One], 1);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Status::Unverified);
+    }
+}
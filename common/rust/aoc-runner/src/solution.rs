@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+// A day's puzzle, split into parsing the input and then solving each part.
+//
+// The input is only parsed once, so both parts share the same model. Days
+// whose parts read the input differently can use the normalised text itself.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+// The parse used by days that don't have a shared model. It only makes sure
+// Windows line endings don't leak into the parts.
+pub fn normalise(input: &str) -> String {
+    input.replace("\r\n", "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalise, Solution};
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input.lines().map(|line| line.parse().expect("a number")).collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u32>) -> u32 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn parts_share_input() {
+        let input = Lines::parse("2\n3\n4");
+        assert_eq!(Lines::part1(&input), 9);
+        assert_eq!(Lines::part2(&input), 24);
+    }
+
+    #[test]
+    fn normalises_line_endings() {
+        assert_eq!(normalise("a\r\nb\r\n"), "a\nb\n");
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use crate::{
+    answers::{self, Answers},
+    client::Client,
+    day::{Day, Part},
+    run::catch,
+    Error,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way.
+    Wrong,
+    // An answer was sent too recently, so this one wasn't checked.
+    RateLimited,
+    // The part has already been solved, so this one wasn't checked.
+    AlreadySolved,
+}
+
+// What the site made of an answer, and how long it wants us to wait before
+// sending another.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+// The message on a response page, without its markup.
+fn message(page: &str) -> String {
+    let article = page.split_once("<article>").map_or(page, |(_, rest)| rest);
+    let article = article.split_once("</article>").map_or(article, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for character in article.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            character if !in_tag => text.push(character),
+            _ => {},
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Waits are either a countdown, like `You have 1m 4s left to wait`, or a
+// penalty for a wrong answer, like `please wait 5 minutes before trying again`.
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((_, rest)) = message.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        let mut seconds = 0;
+        for amount in left.split_whitespace() {
+            let (number, unit) = amount.split_at(amount.len().saturating_sub(1));
+            let unit = match unit {
+                "h" => 60 * 60,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            seconds += number.parse::<u64>().ok()? * unit;
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let lower = message.to_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let number = match words.next()? {
+        "one" | "a" => 1,
+        number => number.parse::<u64>().ok()?,
+    };
+    let unit = match words.next()? {
+        unit if unit.starts_with("second") => 1,
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("hour") => 60 * 60,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(number * unit))
+}
+
+pub fn parse_response(page: &str) -> Result<Outcome, Error> {
+    let message = message(page);
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        return Err(Error::UnexpectedResponse(message));
+    };
+
+    Ok(Outcome { verdict, wait: parse_wait(&message) })
+}
+
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{seconds}s"),
+        (minutes, 0) => format!("{minutes}m"),
+        (minutes, seconds) => format!("{minutes}m {seconds}s"),
+    }
+}
+
+// When the next answer can be sent, which is kept between runs so that we
+// don't ask the site before it is ready.
+pub struct Throttle {
+    path: PathBuf,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf) -> Throttle {
+        Throttle { path }
+    }
+
+    // Where the throttle is kept, such as `~/.local/share/aoc/submit-after` on
+    // Linux.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("aoc").join("submit-after"))
+    }
+
+    pub fn remaining(&self, now: SystemTime) -> Option<Duration> {
+        let after = fs::read_to_string(&self.path).ok()?.trim().parse::<u64>().ok()?;
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Duration::from_secs(after).checked_sub(now).filter(|wait| !wait.is_zero())
+    }
+
+    // The site enforces its own waits, so failing to save one only means it
+    // might be asked too early.
+    pub fn wait(&self, now: SystemTime, wait: Duration) {
+        let after = now.duration_since(UNIX_EPOCH).unwrap_or_default() + wait;
+        if let Some(dir) = self.path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(&self.path, after.as_secs().to_string());
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submitted {
+    // The answer was already verified, so there was no need to send it.
+    Known(String),
+    Sent { answer: String, outcome: Outcome },
+}
+
+// Everything needed to solve a part of a day and send its answer.
+pub struct Submission<'a> {
+    pub year: u16,
+    pub day: &'a Day,
+    pub part: Part,
+    // The year's workspace, which holds `answers.toml`.
+    pub workspace: &'a Path,
+    // The input to solve, which must belong to the account the session is for.
+    pub input: &'a Path,
+    // Whose input it is, when it isn't the day's own, so its answers are kept
+    // with that input's.
+    pub name: Option<&'a str>,
+    pub throttle: &'a Throttle,
+}
+
+// Solve the part, then send its answer unless it's already known. A correct
+// answer is saved to `answers.toml`.
+pub fn submit(submission: &Submission, client: impl FnOnce() -> Result<Client, Error>) -> Result<Submitted, Error> {
+    let Submission { year, day, part, workspace, input, name, throttle } = *submission;
+
+    let answers = Answers::load(workspace)?;
+    let input = fs::read_to_string(input).map_err(|source| Error::ReadInput { path: input.to_owned(), source })?;
+    let answer = catch(|| day.run(&input, &[part]))
+        .map_err(Error::Panic)?
+        .parts
+        .remove(0)
+        .answer;
+
+    if let Some(expected) = answers.get(day.day, name, part) {
+        if expected != answer {
+            return Err(Error::KnownAnswer { answer, expected: expected.to_owned() });
+        }
+        return Ok(Submitted::Known(answer));
+    }
+
+    if let Some(mistake) = answers.mistake(day.day, name, part, &answer) {
+        return Err(Error::KnownMistake { answer, mistake });
+    }
+
+    let now = SystemTime::now();
+    if let Some(wait) = throttle.remaining(now) {
+        return Err(Error::Throttled { wait });
+    }
+
+    let page = client()?.submit(year, day.day, part, &answer)?;
+    let outcome = parse_response(&page)?;
+    if let Some(wait) = outcome.wait {
+        throttle.wait(now, wait);
+    }
+
+    match outcome.verdict {
+        Verdict::Correct => answers::record_answer(workspace, day.day, name, part, &answer)?,
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => {
+            answers::record_wrong(workspace, day.day, name, part, &answer, outcome.verdict)?;
+        },
+        Verdict::RateLimited | Verdict::AlreadySolved => {},
+    }
+
+    Ok(Submitted::Sent { answer, outcome })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+    use super::{format_wait, parse_response, submit, Outcome, Submission, Submitted, Throttle, Verdict};
+    use crate::{
+        answers::{Answers, Mistake, ANSWERS_FILE},
+        client::Client,
+        day::{Day, Part},
+        testing::{serve, TempDir},
+        Error,
+        Solution,
+    };
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input = String;
+        type Answer = usize;
+
+        fn parse(input: &str) -> String {
+            input.trim().to_owned()
+        }
+
+        fn part1(input: &String) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &String) -> usize {
+            input.len() * 2
+        }
+    }
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    fn workspace(name: &str) -> TempDir {
+        let dir = TempDir::new(&format!("submit-{name}"));
+        fs::write(dir.join("input.txt"), "abc").unwrap();
+        dir
+    }
+
+    #[test]
+    fn parses_responses() {
+        let outcome = |message: &str| parse_response(&page(message)).unwrap();
+
+        assert_eq!(
+            outcome("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            Outcome { verdict: Verdict::Correct, wait: None },
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+            Outcome { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) },
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low.  please wait 5 minutes before trying again."),
+            Outcome { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(300)) },
+        );
+        assert_eq!(outcome("That's not the right answer.").verdict, Verdict::Wrong);
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait."),
+            Outcome { verdict: Verdict::RateLimited, wait: Some(Duration::from_secs(64)) },
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?").verdict,
+            Verdict::AlreadySolved,
+        );
+        assert!(matches!(parse_response(&page("Something else")), Err(Error::UnexpectedResponse(_))));
+    }
+
+    #[test]
+    fn formats_waits() {
+        assert_eq!(format_wait(Duration::from_secs(34)), "34s");
+        assert_eq!(format_wait(Duration::from_secs(300)), "5m");
+        assert_eq!(format_wait(Duration::from_secs(64)), "1m 4s");
+    }
+
+    #[test]
+    fn records_correct_answers() {
+        let dir = workspace("correct");
+        let (url, server) = serve("200 OK", "<article><p>That's the right answer!</p></article>");
+        let throttle = Throttle::new(dir.join("submit-after"));
+        let submission = Submission {
+            year: 2023,
+            day: &Day::new::<Length>(5),
+            part: Part::Two,
+            workspace: &dir,
+            input: &dir.join("input.txt"),
+            name: None,
+            throttle: &throttle,
+        };
+
+        let submitted = submit(&submission, || Ok(Client::new(&url, "secret"))).unwrap();
+        assert_eq!(submitted, Submitted::Sent { answer: "6".to_owned(), outcome: Outcome { verdict: Verdict::Correct, wait: None } });
+
+        let request = server.join().unwrap();
+        assert!(request.head.starts_with("POST /2023/day/5/answer "));
+        assert_eq!(request.body, "level=2&answer=6");
+
+        let answers = Answers::parse(&fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap()).unwrap();
+        assert_eq!(answers.get(5, None, Part::Two), Some("6"));
+
+        // Now that it's known, it isn't sent again.
+        let submitted = submit(&submission, || panic!("the client to not be needed")).unwrap();
+        assert_eq!(submitted, Submitted::Known("6".to_owned()));
+    }
+
+    #[test]
+    fn waits_when_asked() {
+        let dir = workspace("throttle");
+        let (url, server) = serve("200 OK", "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>");
+        let throttle = Throttle::new(dir.join("submit-after"));
+        let submission = Submission {
+            year: 2023,
+            day: &Day::new::<Length>(5),
+            part: Part::One,
+            workspace: &dir,
+            input: &dir.join("input.txt"),
+            name: None,
+            throttle: &throttle,
+        };
+
+        let submitted = submit(&submission, || Ok(Client::new(&url, "secret"))).unwrap();
+        server.join().unwrap();
+        assert!(matches!(submitted, Submitted::Sent { outcome: Outcome { verdict: Verdict::TooLow, .. }, .. }));
+
+        let answers = Answers::parse(&fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap()).unwrap();
+        assert_eq!(answers.get(5, None, Part::One), None);
+        assert_eq!(answers.mistake(5, None, Part::One, "3"), Some(Mistake::Repeated));
+
+        // The same answer is never sent twice, even once the wait is over.
+        let error = submit(&submission, || panic!("the client to not be needed")).unwrap_err();
+        assert!(matches!(error, Error::KnownMistake { mistake: Mistake::Repeated, .. }));
+
+        fs::write(dir.join(ANSWERS_FILE), "[day-05]\npart1_too_high = 2\n").unwrap();
+        let error = submit(&submission, || panic!("the client to not be needed")).unwrap_err();
+        assert!(matches!(error, Error::KnownMistake { mistake: Mistake::TooHigh(_), .. }));
+
+        fs::remove_file(dir.join(ANSWERS_FILE)).unwrap();
+        let error = submit(&submission, || panic!("the client to not be needed")).unwrap_err();
+        assert!(matches!(error, Error::Throttled { wait } if wait <= Duration::from_secs(60)));
+    }
+}
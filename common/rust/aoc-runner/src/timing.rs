@@ -0,0 +1,128 @@
+use std::time::Duration;
+use crate::day::{Day, Part};
+
+// Every duration taken for a single step over a number of repeats.
+#[derive(Clone, Debug, Default)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    pub fn push(&mut self, duration: Duration) {
+        self.0.push(duration);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn sorted(&self) -> Vec<Duration> {
+        let mut sorted = self.0.clone();
+        sorted.sort();
+        sorted
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let sorted = self.sorted();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            len if len.is_multiple_of(2) => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            len => sorted[len / 2],
+        }
+    }
+
+    // Uses the nearest rank, so small numbers of repeats report a real sample
+    // rather than one interpolated between two.
+    pub fn p95(&self) -> Duration {
+        let sorted = self.sorted();
+        let rank = (sorted.len() * 95).div_ceil(100);
+        sorted.get(rank.saturating_sub(1)).copied().unwrap_or_default()
+    }
+}
+
+impl From<Vec<Duration>> for Samples {
+    fn from(durations: Vec<Duration>) -> Self {
+        Samples(durations)
+    }
+}
+
+#[derive(Debug)]
+pub struct PartMeasurement {
+    pub part: Part,
+    pub answer: String,
+    pub samples: Samples,
+}
+
+// How long it took to load the input once, then to parse it and solve each
+// part over every repeat.
+#[derive(Debug)]
+pub struct Measurement {
+    pub load: Duration,
+    pub parse: Samples,
+    pub parts: Vec<PartMeasurement>,
+}
+
+impl Measurement {
+    pub fn new(day: &Day, load: Duration, input: &str, parts: &[Part], repeat: usize) -> Measurement {
+        let mut parse = Samples::default();
+        let mut measured: Vec<PartMeasurement> = Vec::new();
+
+        for _ in 0..repeat.max(1) {
+            let execution = day.run(input, parts);
+            parse.push(execution.parse);
+
+            for (index, solved) in execution.parts.into_iter().enumerate() {
+                match measured.get_mut(index) {
+                    Some(measurement) => measurement.samples.push(solved.duration),
+                    None => measured.push(PartMeasurement {
+                        part: solved.part,
+                        answer: solved.answer,
+                        samples: Samples::from(vec![solved.duration]),
+                    }),
+                }
+            }
+        }
+
+        Measurement { load, parse, parts: measured }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::Samples;
+
+    fn millis(values: &[u64]) -> Samples {
+        Samples::from(values.iter().map(|&value| Duration::from_millis(value)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn statistics() {
+        let samples = millis(&[5, 1, 4, 2, 3]);
+        assert_eq!(samples.min(), Duration::from_millis(1));
+        assert_eq!(samples.median(), Duration::from_millis(3));
+        assert_eq!(samples.p95(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn even_median_is_averaged() {
+        assert_eq!(millis(&[4, 1, 2, 3]).median(), Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn p95_uses_nearest_rank() {
+        let samples = millis(&(1..=100).collect::<Vec<_>>());
+        assert_eq!(samples.p95(), Duration::from_millis(95));
+        assert_eq!(millis(&[]).p95(), Duration::ZERO);
+    }
+}
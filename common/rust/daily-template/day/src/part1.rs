@@ -0,0 +1,15 @@
+pub fn process(_input: &str) -> u32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_runner::Solution;
+    use crate::Puzzle;
+    use super::process;
+
+    #[test]
+    fn real_answer() {
+        aoc_runner::check_answer(env!("CARGO_MANIFEST_DIR"), aoc_runner::Part::One, |input| process(&Puzzle::parse(input)));
+    }
+}
@@ -0,0 +1,35 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+pub mod part1;
+pub mod part2;
+
+use aoc_common::Grid;
+use aoc_runner::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Grid<char> {
+        input.parse().unwrap_or_else(|error| panic!("could not parse the grid: {error}"))
+    }
+
+    fn part1(input: &Grid<char>) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Grid<char>) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
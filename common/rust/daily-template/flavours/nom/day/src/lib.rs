@@ -0,0 +1,53 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+pub mod part1;
+pub mod part2;
+
+use aoc_runner::Solution;
+use nom::{character::complete::{self, line_ending}, multi::separated_list1, Parser};
+use nom_supreme::{error::ErrorTree, final_parser::final_parser};
+
+// Parse errors keep the context of every parser that failed, so a bad input
+// says where it went wrong.
+pub type Error<'a> = ErrorTree<&'a str>;
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+pub fn parse_input(input: &str) -> IResult<'_, Vec<u32>> {
+    separated_list1(line_ending, complete::u32).parse(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<u32>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Vec<u32> {
+        final_parser(parse_input)(input.trim_end())
+            .unwrap_or_else(|error: Error<'_>| panic!("could not parse the input: {error}"))
+    }
+
+    fn part1(input: &Vec<u32>) -> u32 {
+        part1::process(input)
+    }
+
+    fn part2(input: &Vec<u32>) -> u32 {
+        part2::process(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_runner::Solution;
+    use super::{parse_input, Puzzle};
+
+    #[test]
+    fn parses_input() {
+        assert_eq!(Puzzle::parse("1\r\n2\r\n"), [1, 2]);
+        assert!(parse_input("x").is_err());
+    }
+
+    #[test]
+    fn examples() {
+        aoc_runner::check_examples::<Puzzle>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
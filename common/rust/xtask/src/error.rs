@@ -0,0 +1,37 @@
+use std::{fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    NoWorkspace(PathBuf),
+    NoTemplate(PathBuf),
+    DayExists(PathBuf),
+    Read { path: PathBuf, source: io::Error },
+    Write { path: PathBuf, source: io::Error },
+    InvalidManifest { path: PathBuf, message: String },
+    NoDayList(PathBuf),
+    Git { command: String, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoWorkspace(path) => write!(f, "there is no workspace for that year, expected `{}`, which needs setting up by hand", path.display()),
+            Error::NoTemplate(path) => write!(f, "there is no daily template, expected `{}`", path.display()),
+            Error::DayExists(path) => write!(f, "the day already exists at `{}`", path.display()),
+            Error::Read { path, source } => write!(f, "could not read `{}`: {source}", path.display()),
+            Error::Write { path, source } => write!(f, "could not write `{}`: {source}", path.display()),
+            Error::InvalidManifest { path, message } => write!(f, "could not parse `{}`: {}", path.display(), message.trim_end()),
+            Error::NoDayList(path) => write!(f, "could not find the list of days in `{}`", path.display()),
+            Error::Git { command, message } => write!(f, "`{command}` failed: {}", message.trim_end()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Read { source, .. } | Error::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
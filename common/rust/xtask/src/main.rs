@@ -0,0 +1,304 @@
+mod error;
+mod register;
+mod template;
+#[cfg(test)]
+mod testing;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command as Process, ExitCode},
+};
+use aoc_runner::parse_day;
+use clap::{Parser, Subcommand};
+use error::Error;
+use template::{Day, Flavour};
+
+#[derive(Debug, Parser)]
+#[command(name = "cargo xtask", about = "Tasks for working on the repository.")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create a day from the shared daily template, and register it with the year's runner.
+    New {
+        /// The year of the puzzle, such as `2024`.
+        year: u16,
+
+        /// The day of the puzzle, as either `5`, `05` or `day-05`.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// The skeleton to start from, which adds the dependencies it needs.
+        #[arg(long, value_enum, default_value_t = Flavour::Plain)]
+        flavour: Flavour,
+
+        /// Switch to a new local branch for the day first, such as `2024/day-05`.
+        #[arg(long)]
+        branch: bool,
+
+        /// Commit the new day, without pushing it anywhere.
+        #[arg(long)]
+        commit: bool,
+    },
+}
+
+// The repository, which this crate sits within as `common/rust/xtask`.
+fn repository() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(3)
+        .expect("xtask to be inside the repository")
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Read { path: path.to_owned(), source })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), Error> {
+    fs::write(path, text).map_err(|source| Error::Write { path: path.to_owned(), source })
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<(), Error> {
+    let command = format!("git {}", args.join(" "));
+    let output = Process::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|error| Error::Git { command: command.clone(), message: error.to_string() })?;
+
+    if !output.status.success() {
+        return Err(Error::Git { command, message: String::from_utf8_lossy(&output.stderr).into_owned() });
+    }
+    Ok(())
+}
+
+// The daily template that every year shares, which is the day's crate along
+// with its entry in `answers.toml`. Each flavour in `flavours` replaces some of
+// the crate's files, and adds the dependencies in its `dependencies.toml`.
+const TEMPLATE: &str = "common/rust/daily-template";
+
+// Render the daily template as `day-XX` within the year's workspace, then add
+// it to the runner and to `answers.toml`. Everything is checked before anything
+// is written, and put back if writing fails, so a failure doesn't leave half a
+// day behind.
+fn new_day(root: &Path, day: Day, flavour: Flavour, branch: bool, commit: bool) -> Result<PathBuf, Error> {
+    let workspace = root.join(day.year.to_string()).join("rust");
+    if !workspace.join("Cargo.toml").is_file() {
+        return Err(Error::NoWorkspace(workspace));
+    }
+
+    let template = root.join(TEMPLATE);
+    if !template.join("day").is_dir() {
+        return Err(Error::NoTemplate(template));
+    }
+    let flavoured = flavour.dir().map(|dir| template.join("flavours").join(dir));
+    if let Some(flavoured) = flavoured.as_ref().filter(|flavoured| !flavoured.join("day").is_dir()) {
+        return Err(Error::NoTemplate(flavoured.clone()));
+    }
+
+    let name = day.name();
+    let day_dir = workspace.join(&name);
+    if day_dir.exists() {
+        return Err(Error::DayExists(day_dir));
+    }
+
+    let mut day_manifest = template::render_text(&read(&template.join("day/Cargo.toml"))?, day);
+    if let Some(flavoured) = &flavoured {
+        let added = flavoured.join("dependencies.toml");
+        day_manifest = register::add_dependencies(&day_manifest, &read(&added)?)
+            .map_err(|message| Error::InvalidManifest { path: added, message })?;
+    }
+
+    let manifest = workspace.join("aoc/Cargo.toml");
+    let original_manifest = read(&manifest)?;
+    let dependencies = register::add_dependency(&original_manifest, &name)
+        .map_err(|message| Error::InvalidManifest { path: manifest.clone(), message })?;
+    let main = workspace.join("aoc/src/main.rs");
+    let original_main = read(&main)?;
+    let days = register::add_day(&original_main, day.day).ok_or_else(|| Error::NoDayList(main.clone()))?;
+
+    let answers = workspace.join("answers.toml");
+    let had_answers = answers.exists();
+    let existing = if had_answers { read(&answers)? } else { String::new() };
+    let entry = template::render_text(&read(&template.join("answers.toml"))?, day);
+    let answered = register::add_answers(&existing, &entry)
+        .map_err(|message| Error::InvalidManifest { path: answers.clone(), message })?;
+
+    let branch_name = format!("{}/{name}", day.year);
+    if branch {
+        git(&workspace, &["switch", "-c", &branch_name])?;
+    }
+
+    let written = (|| {
+        template::render(&template.join("day"), &day_dir, day)?;
+        if let Some(flavoured) = &flavoured {
+            template::render(&flavoured.join("day"), &day_dir, day)?;
+        }
+        write(&day_dir.join("Cargo.toml"), &day_manifest)?;
+        write(&manifest, &dependencies)?;
+        write(&main, &days)?;
+        write(&answers, &answered)
+    })();
+
+    // Go back to how everything was when the day couldn't be written, rather
+    // than leaving part of it behind, or the user on an empty branch.
+    if let Err(error) = written {
+        let _ = fs::remove_dir_all(&day_dir);
+        let _ = fs::write(&manifest, &original_manifest);
+        let _ = fs::write(&main, &original_main);
+        let _ = if had_answers { fs::write(&answers, &existing) } else if answers.is_file() { fs::remove_file(&answers) } else { Ok(()) };
+        if branch {
+            let _ = git(&workspace, &["switch", "-"]);
+            let _ = git(&workspace, &["branch", "-D", &branch_name]);
+        }
+        return Err(error);
+    }
+
+    if commit {
+        git(&workspace, &["add", "--", &name, "aoc/Cargo.toml", "aoc/src/main.rs", "answers.toml"])?;
+        git(&workspace, &["commit", "-m", &format!("Set up {name}.")])?;
+    }
+
+    Ok(day_dir)
+}
+
+fn main() -> ExitCode {
+    let Command::New { year, day, flavour, branch, commit } = Cli::parse().command;
+
+    let root = repository();
+    match new_day(root, Day { year, day }, flavour, branch, commit) {
+        Ok(day_dir) => {
+            let day_dir = day_dir.strip_prefix(root).unwrap_or(&day_dir);
+            println!("Created `{}` and registered it with the runner", day_dir.display());
+            println!("Its input can be fetched with `cargo run --bin aoc -- fetch {year} {day}`");
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use clap::Parser;
+    use super::{git, new_day, Cli, Command};
+    use crate::{error::Error, template::{Day, Flavour}, testing::TempDir};
+
+    const DAY: Day = Day { year: 2024, day: 5 };
+
+    #[test]
+    fn commands() {
+        let cli = Cli::try_parse_from(["xtask", "new", "2024", "day-05", "--commit"]).unwrap();
+        assert!(matches!(cli.command, Command::New { year: 2024, day: 5, flavour: Flavour::Plain, branch: false, commit: true }));
+
+        let cli = Cli::try_parse_from(["xtask", "new", "2024", "5", "--flavour", "nom"]).unwrap();
+        assert!(matches!(cli.command, Command::New { flavour: Flavour::Nom, .. }));
+        assert!(Cli::try_parse_from(["xtask", "new", "2024", "5", "--flavour", "hex"]).is_err());
+        assert!(Cli::try_parse_from(["xtask", "new", "2024", "0"]).is_err());
+    }
+
+    #[test]
+    fn creates_days() {
+        let root = TempDir::new("new");
+        let workspace = root.join("2024/rust");
+        let template = root.join(super::TEMPLATE);
+        fs::create_dir_all(template.join("day/src")).unwrap();
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\n").unwrap();
+        fs::write(template.join("day/Cargo.toml"), "[package]\nname = \"{{project-name}}\"\n").unwrap();
+        fs::write(template.join("day/src/lib.rs"), "// https://adventofcode.com/{{year}}/day/{{day}}\n").unwrap();
+        fs::write(template.join("answers.toml"), "[{{project-name}}]\n").unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), "[package]\nname = \"aoc\"\n\n[dependencies]\n").unwrap();
+        fs::write(workspace.join("aoc/src/main.rs"), "fn main() {}\n").unwrap();
+
+        // Without a list of days nothing is written at all.
+        assert!(matches!(new_day(&root, DAY, Flavour::Plain, false, false), Err(Error::NoDayList(_))));
+        assert!(!workspace.join("day-05").exists());
+
+        fs::write(workspace.join("aoc/src/main.rs"), "fn main() {\n    run(&[\n    ])\n}\n").unwrap();
+        assert_eq!(new_day(&root, DAY, Flavour::Plain, false, false).unwrap(), workspace.join("day-05"));
+        assert_eq!(fs::read_to_string(workspace.join("day-05/Cargo.toml")).unwrap(), "[package]\nname = \"day-05\"\n");
+        assert_eq!(fs::read_to_string(workspace.join("day-05/src/lib.rs")).unwrap(), "// https://adventofcode.com/2024/day/5\n");
+        assert_eq!(fs::read_to_string(workspace.join("answers.toml")).unwrap(), "[day-05]\n");
+        assert!(fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap().contains("day-05 = { path = \"../day-05\" }"));
+        assert!(fs::read_to_string(workspace.join("aoc/src/main.rs")).unwrap().contains("        day!(5, day_05),\n    ])"));
+
+        assert!(matches!(new_day(&root, DAY, Flavour::Plain, false, false), Err(Error::DayExists(_))));
+        assert!(matches!(new_day(&root, Day { year: 2025, day: 1 }, Flavour::Plain, false, false), Err(Error::NoWorkspace(_))));
+
+        // A flavour replaces some of the files, and adds its dependencies.
+        let day = Day { year: 2024, day: 6 };
+        assert!(matches!(new_day(&root, day, Flavour::Nom, false, false), Err(Error::NoTemplate(_))));
+        fs::create_dir_all(template.join("flavours/nom/day/src")).unwrap();
+        fs::write(template.join("flavours/nom/day/src/lib.rs"), "use nom;\n").unwrap();
+        fs::write(template.join("flavours/nom/dependencies.toml"), "nom = \"7.1.3\"\n").unwrap();
+        new_day(&root, day, Flavour::Nom, false, false).unwrap();
+        assert_eq!(fs::read_to_string(workspace.join("day-06/Cargo.toml")).unwrap(), "[package]\nname = \"day-06\"\n\n[dependencies]\nnom = \"7.1.3\"\n");
+        assert_eq!(fs::read_to_string(workspace.join("day-06/src/lib.rs")).unwrap(), "use nom;\n");
+    }
+
+    #[test]
+    fn leaves_no_branch_behind() {
+        let root = TempDir::new("branch");
+        let workspace = root.join("2024/rust");
+        let template = root.join(super::TEMPLATE);
+        fs::create_dir_all(template.join("day/src")).unwrap();
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\n").unwrap();
+        fs::write(template.join("day/Cargo.toml"), "[package]\n").unwrap();
+        fs::write(template.join("answers.toml"), "[{{project-name}}]\n").unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(workspace.join("aoc/src/main.rs"), "fn main() {\n    run(&[\n    ])\n}\n").unwrap();
+
+        // A template that can't be read only fails once the branch exists.
+        fs::write(template.join("day/src/lib.rs"), [0xff, 0xfe]).unwrap();
+
+        git(&root, &["init", "--quiet", "--initial-branch", "main"]).unwrap();
+        git(&root, &["add", "."]).unwrap();
+        git(&root, &["-c", "user.name=xtask", "-c", "user.email=xtask@example.com", "commit", "--quiet", "-m", "Start."]).unwrap();
+
+        assert!(matches!(new_day(&root, DAY, Flavour::Plain, true, false), Err(Error::Read { .. })));
+        let head = std::process::Command::new("git").args(["branch", "--show-current"]).current_dir(&root).output().unwrap();
+        assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "main");
+        assert!(git(&root, &["rev-parse", "--verify", "--quiet", "refs/heads/2024/day-05"]).is_err());
+
+        // Nor is any of the day left behind, so it can be tried again.
+        assert!(!workspace.join("day-05").exists());
+        assert!(!workspace.join("answers.toml").exists());
+        assert_eq!(fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap(), "[dependencies]\n");
+        git(&root, &["diff", "--quiet"]).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn puts_back_the_runner() {
+        let root = TempDir::new("restore");
+        let workspace = root.join("2024/rust");
+        let template = root.join(super::TEMPLATE);
+        fs::create_dir_all(template.join("day/src")).unwrap();
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\n").unwrap();
+        fs::write(template.join("day/Cargo.toml"), "[package]\n").unwrap();
+        fs::write(template.join("day/src/lib.rs"), "").unwrap();
+        fs::write(template.join("answers.toml"), "[{{project-name}}]\n").unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(workspace.join("aoc/src/main.rs"), "fn main() {\n    run(&[\n    ])\n}\n").unwrap();
+
+        // `answers.toml` is the last thing written, and it can't be when it
+        // points into a directory that doesn't exist.
+        std::os::unix::fs::symlink(root.join("missing/answers.toml"), workspace.join("answers.toml")).unwrap();
+
+        assert!(matches!(new_day(&root, DAY, Flavour::Plain, false, false), Err(Error::Write { .. })));
+        assert!(!workspace.join("day-05").exists());
+        assert_eq!(fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap(), "[dependencies]\n");
+        assert_eq!(fs::read_to_string(workspace.join("aoc/src/main.rs")).unwrap(), "fn main() {\n    run(&[\n    ])\n}\n");
+        assert!(fs::symlink_metadata(workspace.join("answers.toml")).is_ok());
+    }
+}
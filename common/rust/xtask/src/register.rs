@@ -0,0 +1,149 @@
+// Add each of the dependencies in `dependencies`, which is written like the
+// `[dependencies]` table itself, keeping them in order. A dependency that is
+// already there is left as it is.
+pub fn add_dependencies(manifest: &str, dependencies: &str) -> Result<String, String> {
+    let mut document = manifest.parse::<toml_edit::DocumentMut>().map_err(|error| error.message().to_owned())?;
+    let added = dependencies.parse::<toml_edit::DocumentMut>().map_err(|error| error.message().to_owned())?;
+    let dependencies = document
+        .entry("dependencies")
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .ok_or("`dependencies` is not a table")?;
+
+    for (name, dependency) in added.iter() {
+        if !dependencies.contains_key(name) {
+            dependencies.insert(name, dependency.clone());
+        }
+    }
+    dependencies.sort_values();
+
+    Ok(document.to_string())
+}
+
+// Add a day to the runner's dependencies, as `day-05 = { path = "../day-05" }`.
+pub fn add_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    add_dependencies(manifest, &format!("{name} = {{ path = \"../{name}\" }}"))
+}
+
+fn last_position(table: &toml_edit::Table) -> Option<usize> {
+    table
+        .iter()
+        .filter_map(|(_, item)| item.as_table())
+        .map(|table| table.position().max(last_position(table)))
+        .max()
+        .flatten()
+}
+
+// Add the template's entry for a day to the workspace's `answers.toml`. New
+// days are nearly always made in order, so it goes at the end, and a day that
+// already has answers is left alone.
+pub fn add_answers(answers: &str, entry: &str) -> Result<String, String> {
+    let mut document = answers.parse::<toml_edit::DocumentMut>().map_err(|error| error.message().to_owned())?;
+    let entry = entry.parse::<toml_edit::DocumentMut>().map_err(|error| error.message().to_owned())?;
+
+    for (key, item) in entry.iter() {
+        if !document.contains_key(key) {
+            let mut item = item.clone();
+            if let Some(table) = item.as_table_mut() {
+                // Tables are written in the order of their positions, and this
+                // one still has its position from the template.
+                if let Some(last) = last_position(document.as_table()) {
+                    table.set_position(last + 1);
+                    table.decor_mut().set_prefix("\n");
+                }
+            }
+            document.insert(key, item);
+        }
+    }
+
+    Ok(document.to_string())
+}
+
+fn registered_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("day!(")?.split(',').next()?.trim().parse().ok()
+}
+
+// Add `day!(5, day_05),` to the list of days in the runner's `main.rs`, in
+// order. A day that is already there is left alone, and `None` means there was
+// no list to add it to.
+pub fn add_day(main: &str, day: u8) -> Option<String> {
+    let mut lines = main.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, registered_day(line)?)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|&(_, registered)| registered == day) {
+        return Some(main.to_owned());
+    }
+
+    let index = match (days.iter().find(|&&(_, registered)| registered > day), days.last()) {
+        (Some(&(index, _)), _) => index,
+        (None, Some(&(index, _))) => index + 1,
+        (None, None) => lines.iter().position(|line| line.trim_start().starts_with("])"))?,
+    };
+    let indent = days.first().map_or("        ", |&(index, _)| {
+        let line = lines[index];
+        &line[..line.len() - line.trim_start().len()]
+    });
+
+    let registration = format!("{indent}day!({day}, day_{day:02}),");
+    lines.insert(index, &registration);
+
+    let mut text = lines.join("\n");
+    if main.ends_with('\n') {
+        text.push('\n');
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_answers, add_day, add_dependencies, add_dependency};
+
+    const MAIN: &str = "use aoc_runner::day;
+
+fn main() -> std::process::ExitCode {
+    aoc_runner::main(2024, env!(\"CARGO_MANIFEST_DIR\"), &[
+        day!(1, day_01),
+        day!(3, day_03),
+    ])
+}
+";
+
+    #[test]
+    fn adds_dependencies() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-runner = { path = \"../../../common/rust/aoc-runner\" }\nday-03 = { path = \"../day-03\" }\n";
+        let added = add_dependency(manifest, "day-02").unwrap();
+        assert!(added.ends_with("aoc-runner = { path = \"../../../common/rust/aoc-runner\" }\nday-02 = { path = \"../day-02\" }\nday-03 = { path = \"../day-03\" }\n"));
+        assert_eq!(add_dependency(&added, "day-02").unwrap(), added);
+
+        let manifest = "[package]\nname = \"day-05\"\n\n[dependencies]\naoc-runner = { path = \"../../../common/rust/aoc-runner\" }\n";
+        let added = add_dependencies(manifest, "nom-supreme = \"0.8.0\"\nnom = \"7.1.3\"\naoc-runner = \"1.0\"\n").unwrap();
+        assert!(added.ends_with("aoc-runner = { path = \"../../../common/rust/aoc-runner\" }\nnom = \"7.1.3\"\nnom-supreme = \"0.8.0\"\n"));
+    }
+
+    #[test]
+    fn adds_answers() {
+        let answers = "# Answers.\n\n[day-01]\npart1 = 1\n\n[day-01.inputs.alice]\npart1 = 2\n";
+        let added = add_answers(answers, "[day-02]\n").unwrap();
+        assert_eq!(added, format!("{answers}\n[day-02]\n"));
+        assert_eq!(add_answers(&added, "[day-01]\n").unwrap(), added);
+        assert!(add_answers(answers, "[day-02").is_err());
+        assert_eq!(add_answers("", "[day-01]\n").unwrap(), "[day-01]\n");
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let added = add_day(MAIN, 2).unwrap();
+        assert!(added.contains("        day!(1, day_01),\n        day!(2, day_02),\n        day!(3, day_03),\n    ])\n"));
+        assert_eq!(add_day(&added, 2).unwrap(), added);
+
+        let added = add_day(MAIN, 12).unwrap();
+        assert!(added.contains("        day!(3, day_03),\n        day!(12, day_12),\n    ])\n"));
+
+        let empty = MAIN.replace("        day!(1, day_01),\n        day!(3, day_03),\n", "");
+        assert!(add_day(&empty, 1).unwrap().contains("&[\n        day!(1, day_01),\n    ])"));
+        assert_eq!(add_day("fn main() {}\n", 1), None);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{fs, path::Path};
+use crate::error::Error;
+
+// The day that a template is being filled in for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Day {
+    // The name of the day's crate, such as `day-05`.
+    pub fn name(self) -> String {
+        format!("day-{:02}", self.day)
+    }
+}
+
+// The skeletons that a day can start from, beyond the plain one that leaves
+// the parsing to each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Flavour {
+    Plain,
+    Nom,
+    Grid,
+}
+
+impl Flavour {
+    // The flavour's directory within the template's `flavours`.
+    pub fn dir(self) -> Option<&'static str> {
+        match self {
+            Flavour::Plain => None,
+            Flavour::Nom => Some("nom"),
+            Flavour::Grid => Some("grid"),
+        }
+    }
+}
+
+// Fill in the placeholders the template uses. The names are the ones that
+// `cargo generate` used, so older days read the same.
+pub fn render_text(text: &str, day: Day) -> String {
+    let name = day.name();
+    text.replace("{{project-name}}", &name)
+        .replace("{{crate_name}}", &name.replace('-', "_"))
+        .replace("{{year}}", &day.year.to_string())
+        .replace("{{day}}", &day.day.to_string())
+}
+
+// Copy the template to `to`, filling in the day in every file and in the names
+// of the files themselves.
+pub fn render(template: &Path, to: &Path, day: Day) -> Result<(), Error> {
+    let read = |source| Error::Read { path: template.to_owned(), source };
+    let mut entries = fs::read_dir(template)
+        .map_err(read)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read)?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    fs::create_dir_all(to).map_err(|source| Error::Write { path: to.to_owned(), source })?;
+    for entry in entries {
+        let path = entry.path();
+        let target = to.join(render_text(&entry.file_name().to_string_lossy(), day));
+
+        if path.is_dir() {
+            render(&path, &target, day)?;
+            continue;
+        }
+
+        let text = fs::read_to_string(&path).map_err(|source| Error::Read { path: path.clone(), source })?;
+        fs::write(&target, render_text(&text, day)).map_err(|source| Error::Write { path: target.clone(), source })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{render, render_text, Day};
+    use crate::testing::TempDir;
+
+    const DAY: Day = Day { year: 2024, day: 5 };
+
+    #[test]
+    fn fills_in_names() {
+        assert_eq!(render_text("name = \"{{project-name}}\"\nuse {{crate_name}};\n", DAY), "name = \"day-05\"\nuse day_05;\n");
+        assert_eq!(render_text("// https://adventofcode.com/{{year}}/day/{{day}}", DAY), "// https://adventofcode.com/2024/day/5");
+    }
+
+    #[test]
+    fn copies_templates() {
+        let dir = TempDir::new("template");
+        fs::create_dir_all(dir.join("template/src")).unwrap();
+        fs::write(dir.join("template/Cargo.toml"), "name = \"{{project-name}}\"\n").unwrap();
+        fs::write(dir.join("template/src/lib.rs"), "pub mod part1;\n").unwrap();
+
+        render(&dir.join("template"), &dir.join("day-05"), DAY).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("day-05/Cargo.toml")).unwrap(), "name = \"day-05\"\n");
+        assert_eq!(fs::read_to_string(dir.join("day-05/src/lib.rs")).unwrap(), "pub mod part1;\n");
+    }
+}